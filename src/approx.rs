@@ -0,0 +1,265 @@
+//! Approximate equality for floating point scalars, `Vector` and `Matrix`,
+//! and the `assert_approx_eq!` macro built on it.
+
+use crate::types::{Complex, Matrix, Scalar, Vector};
+use std::fmt;
+
+/// How far apart two values may be and still count as equal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Approx {
+    /// `|a - b| ≤ ε`
+    Absolute(f64),
+    /// `|a - b| ≤ ε·max(|a|, |b|)`
+    Relative(f64),
+    /// At most `n` representable values apart, applied to each component of
+    /// a complex number.
+    Ulps(u32),
+}
+
+impl Default for Approx {
+    /// Four units in the last place, enough to absorb the rounding of a
+    /// few operations.
+    fn default() -> Self {
+        Approx::Ulps(4)
+    }
+}
+
+/// Values that can be compared up to an `Approx` tolerance.
+///
+/// Two values of different sizes are never approximately equal. NaN is
+/// never approximately equal to anything, infinities only to themselves.
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, approx: Approx) -> bool;
+
+    /// `self - other` element-wise, for assertion messages.
+    fn diff(&self, other: &Self) -> String;
+}
+
+macro_rules! impl_approx_float {
+    ($($t:ty => $bits:ty, $wide:ty);*) => {
+        $(
+            impl ApproxEq for $t {
+                fn approx_eq(&self, other: &Self, approx: Approx) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_nan() || other.is_nan() || self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+                    let difference = (*self as f64 - *other as f64).abs();
+                    match approx {
+                        Approx::Absolute(epsilon) => difference <= epsilon,
+                        Approx::Relative(epsilon) => {
+                            difference <= epsilon * (*self as f64).abs().max((*other as f64).abs())
+                        }
+                        Approx::Ulps(ulps) => {
+                            // maps the bit patterns onto integers ordered like the floats,
+                            // with both zeros at 0
+                            let ordered = |x: $t| {
+                                let bits = x.to_bits() as $bits as $wide;
+                                if bits < 0 {
+                                    <$bits>::MIN as $wide - bits
+                                } else {
+                                    bits
+                                }
+                            };
+                            (ordered(*self) - ordered(*other)).abs() <= ulps as $wide
+                        }
+                    }
+                }
+
+                fn diff(&self, other: &Self) -> String {
+                    format!("{}", self - other)
+                }
+            }
+
+            impl ApproxEq for Complex<$t> {
+                /// `Absolute` and `Relative` compare moduli, `Ulps` each component.
+                fn approx_eq(&self, other: &Self, approx: Approx) -> bool {
+                    let modulus = |z: &Complex<$t>| (z.re as f64).hypot(z.im as f64);
+                    let difference =
+                        (self.re as f64 - other.re as f64).hypot(self.im as f64 - other.im as f64);
+                    match approx {
+                        Approx::Absolute(epsilon) => difference <= epsilon,
+                        Approx::Relative(epsilon) => {
+                            difference <= epsilon * modulus(self).max(modulus(other))
+                        }
+                        Approx::Ulps(_) => {
+                            self.re.approx_eq(&other.re, approx)
+                                && self.im.approx_eq(&other.im, approx)
+                        }
+                    }
+                }
+
+                fn diff(&self, other: &Self) -> String {
+                    format!("{}", *self - *other)
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_float!(f32 => i32, i64; f64 => i64, i128);
+
+impl<T: ApproxEq + ?Sized> ApproxEq for &T {
+    fn approx_eq(&self, other: &Self, approx: Approx) -> bool {
+        (**self).approx_eq(*other, approx)
+    }
+
+    fn diff(&self, other: &Self) -> String {
+        (**self).diff(*other)
+    }
+}
+
+/// Writes `items` separated by commas, as the `Display` impls do.
+fn join(f: &mut impl fmt::Write, items: impl Iterator<Item = String>) -> fmt::Result {
+    for (index, item) in items.enumerate() {
+        if index != 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{item}")?;
+    }
+    Ok(())
+}
+
+impl<K: ApproxEq + Scalar> ApproxEq for Vector<K> {
+    fn approx_eq(&self, other: &Self, approx: Approx) -> bool {
+        self.size() == other.size()
+            && self
+                .positions
+                .iter()
+                .zip(other.positions.iter())
+                .all(|(a, b)| a.approx_eq(b, approx))
+    }
+
+    fn diff(&self, other: &Self) -> String {
+        if self.size() != other.size() {
+            return format!("sizes differ: {} and {}", self.size(), other.size());
+        }
+        let mut result = String::from("[");
+        let items = self.positions.iter().zip(other.positions.iter());
+        join(&mut result, items.map(|(a, b)| a.diff(b))).expect("writing to a String");
+        result.push(']');
+        result
+    }
+}
+
+impl<K: ApproxEq + Scalar> ApproxEq for Matrix<K> {
+    fn approx_eq(&self, other: &Self, approx: Approx) -> bool {
+        self.shape() == other.shape()
+            && self
+                .as_slice()
+                .iter()
+                .zip(other.as_slice())
+                .all(|(a, b)| a.approx_eq(b, approx))
+    }
+
+    fn diff(&self, other: &Self) -> String {
+        if self.shape() != other.shape() {
+            return format!("shapes differ: {:?} and {:?}", self.shape(), other.shape());
+        }
+        let cols = self.shape().1;
+        let mut result = String::from("[\n");
+        for row in 0..self.shape().0 {
+            let items = (0..cols).map(|column| {
+                let index = row * cols + column;
+                self.as_slice()[index].diff(&other.as_slice()[index])
+            });
+            result.push('[');
+            join(&mut result, items).expect("writing to a String");
+            result.push_str("]\n");
+        }
+        result.push(']');
+        result
+    }
+}
+
+/// Asserts that two values are equal up to an `Approx` tolerance, by
+/// default `Approx::default()`, printing both values and their difference
+/// on failure.
+///
+/// ```
+/// use matrix::{assert_approx_eq, Approx, Vector};
+///
+/// let u = Vector::from(&[1., 2., 2.]);
+/// assert_approx_eq!(u.norm(), 3.);
+/// assert_approx_eq!(0.1 + 0.2, 0.3_f64, Approx::Absolute(1e-12));
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::approx::Approx::default())
+    };
+    ($left:expr, $right:expr, $approx:expr $(,)?) => {
+        match (&$left, &$right, $approx) {
+            (left, right, approx) => {
+                if !$crate::approx::ApproxEq::approx_eq(left, right, approx) {
+                    panic!(
+                        "assertion `left ≈ right` failed ({:?})\n  left: {}\n right: {}\n  diff: {}",
+                        approx,
+                        left,
+                        right,
+                        $crate::approx::ApproxEq::diff(left, right)
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn approx_scalars() {
+        assert!((0.1_f64 + 0.2).approx_eq(&0.3, Approx::default()));
+        assert!(!(0.1_f64 + 0.2).approx_eq(&0.3, Approx::Ulps(0)));
+        assert!(1_f32.approx_eq(&1.001, Approx::Absolute(1e-2)));
+        assert!(!1_f32.approx_eq(&1.001, Approx::Absolute(1e-4)));
+        assert!(1e6_f64.approx_eq(&(1e6 + 1.), Approx::Relative(1e-5)));
+        assert!(!1e-6_f64.approx_eq(&2e-6, Approx::Relative(1e-5)));
+
+        let next = f32::from_bits(1_f32.to_bits() + 2);
+        assert!(1_f32.approx_eq(&next, Approx::Ulps(2)));
+        assert!(!1_f32.approx_eq(&next, Approx::Ulps(1)));
+        // the zeros are one ulp away from the smallest subnormals
+        assert!(0_f64.approx_eq(&-0_f64, Approx::Ulps(0)));
+        assert!((-f64::from_bits(1)).approx_eq(&f64::from_bits(1), Approx::Ulps(2)));
+
+        assert!(!f64::NAN.approx_eq(&f64::NAN, Approx::Absolute(1.)));
+        assert!(f64::INFINITY.approx_eq(&f64::INFINITY, Approx::Ulps(0)));
+        assert!(!f64::INFINITY.approx_eq(&f64::MAX, Approx::Relative(1.)));
+
+        let z = Complex::new(1., 1.);
+        assert!(z.approx_eq(&Complex::new(1., 1. + 1e-10), Approx::Absolute(1e-9)));
+        assert!(!z.approx_eq(&Complex::new(1., 1.1), Approx::Relative(1e-3)));
+    }
+
+    #[test]
+    fn approx_vectors_and_matrices() {
+        let u = Vector::from(&[1., 2.]);
+        assert!(u.approx_eq(&Vector::from(&[1. + 1e-13, 2.]), Approx::Absolute(1e-12)));
+        assert!(!u.approx_eq(&Vector::from(&[1., 2., 3.]), Approx::Absolute(1.)));
+        assert_eq!(u.diff(&Vector::from(&[0.5, 2.])), "[0.5,0]");
+
+        let a = Matrix::from(&[&[1., 2.], &[3., 4.]]);
+        let b = Matrix::from(&[&[1., 2.], &[3., 3.5]]);
+        assert!(a.approx_eq(&b, Approx::Relative(0.2)));
+        assert!(!a.approx_eq(&b, Approx::default()));
+        assert_eq!(a.diff(&b), "[\n[0,0]\n[0,0.5]\n]");
+        assert_eq!(
+            a.diff(&Matrix::zeros(1, 2)),
+            "shapes differ: (2, 2) and (1, 2)"
+        );
+        crate::assert_approx_eq!(a, a.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "diff: [\n[0,0]\n[0,0.5]\n]")]
+    fn assert_approx_eq_message() {
+        let a = Matrix::from(&[&[1., 2.], &[3., 4.]]);
+        let b = Matrix::from(&[&[1., 2.], &[3., 3.5]]);
+        crate::assert_approx_eq!(a, b, Approx::Absolute(0.1));
+    }
+}
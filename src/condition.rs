@@ -0,0 +1,324 @@
+use crate::types::{Field, Matrix, Real, Scalar, Vector};
+use crate::{LinalgError, Lu};
+
+/// Matrix norms a condition number can be measured in. The 2-norm one is
+/// `Svd::condition_number`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Norm {
+    /// Largest column sum of moduli.
+    One,
+    /// Largest row sum of moduli.
+    Infinity,
+    /// Square root of the sum of squared moduli.
+    Frobenius,
+}
+
+/// Up to this size `condition_number` inverts the matrix to get `‖A⁻¹‖`
+/// exactly; above it the `O(n³)` inverse gives way to the `O(n²)` estimate.
+const EXACT_CONDITION_SIZE: usize = 32;
+
+/// Hager's iteration rarely needs more than two or three steps.
+const MAX_ESTIMATE_ITERATIONS: usize = 5;
+
+fn norm_1<K: Scalar>(v: &Vector<K>) -> K::Real {
+    let mut result = K::Real::zero();
+    for &value in &v.positions {
+        result = result + value.abs();
+    }
+    result
+}
+
+/// Hager's estimate of `‖A⁻¹‖₁` from products with `A⁻¹` and `A⁻ᴴ` only,
+/// with Higham's extra alternating-sign test vector for the matrices that
+/// fool the iteration. Never larger than the true norm.
+fn estimate_inverse_norm_1<K: Field>(
+    size: usize,
+    solve: impl Fn(&Vector<K>) -> Result<Vector<K>, LinalgError>,
+    solve_adjoint: impl Fn(&Vector<K>) -> Result<Vector<K>, LinalgError>,
+) -> Result<K::Real, LinalgError> {
+    if size == 0 {
+        return Ok(K::Real::zero());
+    }
+    let n = K::Real::from_usize(size);
+    let mut x = Vector {
+        positions: vec![K::from_real(n.recip()); size],
+    };
+    let mut estimate = K::Real::zero();
+    let mut previous = None;
+    for iteration in 0..MAX_ESTIMATE_ITERATIONS {
+        let y = solve(&x)?;
+        let norm = norm_1(&y);
+        if iteration > 0 && norm <= estimate {
+            break;
+        }
+        estimate = norm;
+        // the subgradient of ‖y‖₁
+        let signs = y
+            .positions
+            .iter()
+            .map(|&value| {
+                if value.is_zero() {
+                    K::one()
+                } else {
+                    value / K::from_real(value.abs())
+                }
+            })
+            .collect();
+        let z = solve_adjoint(&Vector { positions: signs })?;
+        let mut j = 0;
+        for i in 1..size {
+            if z.positions[i].abs() > z.positions[j].abs() {
+                j = i;
+            }
+        }
+        let mut slope = K::Real::zero();
+        for (&zi, &xi) in z.positions.iter().zip(&x.positions) {
+            slope = slope + (zi.conj() * xi).re();
+        }
+        if z.positions[j].abs() <= slope || previous == Some(j) {
+            break;
+        }
+        previous = Some(j);
+        x = Vector {
+            positions: vec![K::zero(); size],
+        };
+        x.positions[j] = K::one();
+    }
+
+    let mut alternating = Vector {
+        positions: vec![K::one(); size],
+    };
+    if size > 1 {
+        let last = K::Real::from_usize(size - 1);
+        for (i, value) in alternating.positions.iter_mut().enumerate() {
+            let magnitude = K::from_real(K::Real::one() + K::Real::from_usize(i) / last);
+            *value = if i % 2 == 0 { magnitude } else { -magnitude };
+        }
+    }
+    let three = K::Real::from_usize(3);
+    let two = K::Real::from_usize(2);
+    let alternating = two * norm_1(&solve(&alternating)?) / (three * n);
+    Ok(if alternating > estimate {
+        alternating
+    } else {
+        estimate
+    })
+}
+
+impl<K: Scalar> Matrix<K> {
+    /// `‖A‖` in the given norm; a zero-sized matrix has norm zero.
+    pub fn norm(&self, norm: Norm) -> K::Real {
+        let (rows, cols) = self.shape();
+        let largest = |sums: Vec<K::Real>| {
+            sums.into_iter().fold(
+                K::Real::zero(),
+                |max, sum| if sum > max { sum } else { max },
+            )
+        };
+        match norm {
+            Norm::One => largest(
+                (0..cols)
+                    .map(|column| {
+                        (0..rows).fold(K::Real::zero(), |sum, row| sum + self[(row, column)].abs())
+                    })
+                    .collect(),
+            ),
+            Norm::Infinity => largest(
+                self.rows()
+                    .map(|row| {
+                        row.iter()
+                            .fold(K::Real::zero(), |sum, value| sum + value.abs())
+                    })
+                    .collect(),
+            ),
+            Norm::Frobenius => {
+                let mut sum = K::Real::zero();
+                for &value in self.as_slice() {
+                    sum = sum + value.abs() * value.abs();
+                }
+                sum.sqrt()
+            }
+        }
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    /// `κ(A) = ‖A‖·‖A⁻¹‖`, how much relative errors in `b` can grow in the
+    /// solution of `Ax = b`. Exact up to 32 × 32, and for the Frobenius
+    /// norm; larger matrices get `Lu::inverse_norm_estimate`, usually
+    /// within a factor of 3 of the truth and never above it.
+    ///
+    /// Fails with `NotSquare` or `Singular`, at the default tolerance.
+    pub fn condition_number(&self, norm: Norm) -> Result<K::Real, LinalgError> {
+        let lu = self.lu()?;
+        Ok(self.norm(norm) * inverse_norm(&lu, norm)?)
+    }
+
+    /// `inverse` along with the condition number it makes exact.
+    pub fn inverse_with_condition(&self, norm: Norm) -> Result<(Matrix<K>, K::Real), LinalgError> {
+        let inverse = self.lu()?.inverse()?;
+        let condition = self.norm(norm) * inverse.norm(norm);
+        Ok((inverse, condition))
+    }
+
+    /// Solves `Ax = b` through one LU factorization, also used for
+    /// `condition_number`. Unlike `solve`, a singular `A` is always an
+    /// error, its condition number being infinite.
+    pub fn solve_with_condition(
+        &self,
+        b: &Vector<K>,
+        norm: Norm,
+    ) -> Result<(Vector<K>, K::Real), LinalgError> {
+        let lu = self.lu()?;
+        let value = lu.solve(b)?;
+        let condition = self.norm(norm) * inverse_norm(&lu, norm)?;
+        Ok((value, condition))
+    }
+}
+
+fn inverse_norm<K: Field>(lu: &Lu<K>, norm: Norm) -> Result<K::Real, LinalgError> {
+    if lu.size() <= EXACT_CONDITION_SIZE {
+        Ok(lu.inverse()?.norm(norm))
+    } else {
+        lu.inverse_norm_estimate(norm)
+    }
+}
+
+impl<K: Field> Lu<K> {
+    /// Estimate of `‖A⁻¹‖` in `O(n²)` from a few solves with the factors.
+    /// The infinity norm is the one norm of `A⁻ᴴ`; the Frobenius norm has
+    /// no cheap estimate and is computed from the inverse.
+    pub fn inverse_norm_estimate(&self, norm: Norm) -> Result<K::Real, LinalgError> {
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        let solve = |b: &Vector<K>| self.solve(b);
+        let solve_adjoint = |b: &Vector<K>| self.solve_adjoint(b);
+        match norm {
+            Norm::One => estimate_inverse_norm_1(self.size(), solve, solve_adjoint),
+            Norm::Infinity => estimate_inverse_norm_1(self.size(), solve_adjoint, solve),
+            Norm::Frobenius => Ok(self.inverse()?.norm(Norm::Frobenius)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_approx_eq, Approx, Complex, Rational};
+
+    fn hilbert(size: usize) -> Matrix<f64> {
+        let mut h = Matrix::zeros(size, size);
+        for i in 0..size {
+            for j in 0..size {
+                h[(i, j)] = 1. / (i + j + 1) as f64;
+            }
+        }
+        h
+    }
+
+    /// `2` on the diagonal, `-1` beside it: `κ₁` grows like `n²/2`.
+    fn second_difference(size: usize) -> Matrix<f64> {
+        let mut a = Matrix::zeros(size, size);
+        for i in 0..size {
+            a[(i, i)] = 2.;
+            if i > 0 {
+                a[(i, i - 1)] = -1.;
+                a[(i - 1, i)] = -1.;
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn matrix_norms() {
+        let a = Matrix::from(&[&[1., -2.], &[-3., 4.]]);
+        assert_eq!(a.norm(Norm::One), 6.);
+        assert_eq!(a.norm(Norm::Infinity), 7.);
+        assert_approx_eq!(a.norm(Norm::Frobenius), 30_f64.sqrt());
+
+        let z = Matrix::from(&[&[Complex::new(3., 4.), Complex::new(0., 1.)]]);
+        assert_eq!(z.norm(Norm::One), 5.);
+        assert_eq!(z.norm(Norm::Infinity), 6.);
+    }
+
+    #[test]
+    fn condition_number_exact() {
+        let r = Rational::from;
+        let a = Matrix::from(&[&[r(2), r(1)], &[r(1), r(1)]]);
+        // A⁻¹ = [[1, -1], [-1, 2]]
+        assert_eq!(a.condition_number(Norm::One), Ok(r(9)));
+        assert_eq!(a.condition_number(Norm::Infinity), Ok(r(9)));
+
+        let identity: Matrix<f64> = Matrix::identity(4);
+        assert_eq!(identity.condition_number(Norm::One), Ok(1.));
+        assert_approx_eq!(identity.condition_number(Norm::Frobenius).unwrap(), 4.);
+
+        // κ∞ of the 5 × 5 Hilbert matrix is 943656
+        let h = hilbert(5);
+        let condition = h.condition_number(Norm::Infinity).unwrap();
+        assert_approx_eq!(condition, 943_656., Approx::Relative(1e-6));
+
+        assert_eq!(
+            Matrix::from(&[&[1., 2.], &[2., 4.]]).condition_number(Norm::One),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            Matrix::from(&[&[1., 2.]]).condition_number(Norm::One),
+            Err(LinalgError::NotSquare { shape: (1, 2) })
+        );
+    }
+
+    #[test]
+    fn condition_number_estimate() {
+        for a in [hilbert(6), second_difference(10)] {
+            let lu = a.lu().unwrap();
+            let inverse = lu.inverse().unwrap();
+            for norm in [Norm::One, Norm::Infinity] {
+                let exact = inverse.norm(norm);
+                let estimate = lu.inverse_norm_estimate(norm).unwrap();
+                assert!(estimate <= exact * (1. + 1e-10));
+                assert!(estimate >= exact / 3., "{estimate} against {exact}");
+            }
+        }
+
+        // past the exact size; the inverse has positive entries, for which
+        // the iteration finds the largest column sum exactly
+        let a = second_difference(40);
+        let exact = a.norm(Norm::One) * a.lu().unwrap().inverse().unwrap().norm(Norm::One);
+        let estimate = a.condition_number(Norm::One).unwrap();
+        assert_approx_eq!(estimate, exact, Approx::Relative(1e-10));
+    }
+
+    #[test]
+    fn inverse_and_solve_with_condition() {
+        let a = Matrix::from(&[&[4., 3.], &[6., 3.]]);
+        let (inverse, condition) = a.inverse_with_condition(Norm::One).unwrap();
+        assert_approx_eq!(&a * &inverse, Matrix::identity(2), Approx::Absolute(1e-15));
+        assert_approx_eq!(condition, a.condition_number(Norm::One).unwrap());
+
+        let (x, condition) = a
+            .solve_with_condition(&Vector::from(&[10., 12.]), Norm::Infinity)
+            .unwrap();
+        assert_approx_eq!(x, Vector::from(&[1., 2.]));
+        // ‖A‖∞ = 9, ‖A⁻¹‖∞ = 5/3
+        assert_approx_eq!(condition, 15., Approx::Relative(1e-15));
+
+        // about 13 of the 16 digits of an f64 inverse of H₁₀ are lost
+        let h = hilbert(10);
+        let (_, condition) = h.inverse_with_condition(Norm::One).unwrap();
+        assert!(condition > 1e12);
+
+        let singular = Matrix::from(&[&[1., 2.], &[2., 4.]]);
+        assert_eq!(
+            singular
+                .solve_with_condition(&Vector::from(&[1., 2.]), Norm::One)
+                .map(|(_, condition)| condition),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            singular.inverse_with_condition(Norm::One),
+            Err(LinalgError::Singular)
+        );
+    }
+}
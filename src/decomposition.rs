@@ -0,0 +1,6 @@
+pub mod cholesky;
+pub mod eigen;
+pub mod hessenberg;
+pub mod lu;
+pub mod qr;
+pub mod svd;
@@ -0,0 +1,241 @@
+use crate::types::{Float, Matrix, Scalar, Vector};
+use crate::{LinalgError, Norm};
+use std::cmp::Ordering;
+
+/// `A = LLᴴ` factorization of a Hermitian (for real scalars, symmetric)
+/// positive definite matrix, with `L` lower triangular and a positive real
+/// diagonal.
+///
+/// Defined for real and complex floating point entries only: the pivots
+/// need a true square root, which integers and `Rational` lack.
+///
+/// ```compile_fail
+/// use matrix::Matrix;
+///
+/// Matrix::from(&[&[2, 1], &[1, 2]]).cholesky();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cholesky<K> {
+    l: Matrix<K>,
+}
+
+impl<K: Scalar> Matrix<K>
+where
+    K::Real: Float,
+{
+    /// Fails with `NotSymmetric` when `A` differs from `Aᴴ` by more than
+    /// machine precision relative to its norm, as `symmetric_eigen` does,
+    /// and with `NotPositiveDefinite` as soon as a pivot is not strictly
+    /// positive.
+    pub fn cholesky(&self) -> Result<Cholesky<K>, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let size = self.shape().0;
+        let threshold = K::epsilon() * self.norm(Norm::Frobenius);
+        for i in 0..size {
+            for j in 0..=i {
+                if (self[(i, j)] - self[(j, i)].conj()).abs() > threshold {
+                    return Err(LinalgError::NotSymmetric);
+                }
+            }
+        }
+        let mut l: Matrix<K> = Matrix::zeros(size, size);
+
+        for j in 0..size {
+            let mut diagonal = self[(j, j)];
+            for k in 0..j {
+                diagonal = diagonal - l[(j, k)] * l[(j, k)].conj();
+            }
+            let diagonal = diagonal.re();
+            // also rejects NaN pivots
+            if diagonal.partial_cmp(&K::Real::zero()) != Some(Ordering::Greater) {
+                return Err(LinalgError::NotPositiveDefinite);
+            }
+            let diagonal = diagonal.sqrt();
+            l[(j, j)] = K::from_real(diagonal);
+
+            for i in j + 1..size {
+                let mut value = self[(i, j)];
+                for k in 0..j {
+                    value = value - l[(i, k)] * l[(j, k)].conj();
+                }
+                l[(i, j)] = value / K::from_real(diagonal);
+            }
+        }
+        Ok(Cholesky { l })
+    }
+}
+
+impl<K: Scalar> Cholesky<K>
+where
+    K::Real: Float,
+{
+    pub fn l(&self) -> &Matrix<K> {
+        &self.l
+    }
+
+    pub fn size(&self) -> usize {
+        self.l.shape().0
+    }
+
+    /// The product of the squared diagonal of `L`, real and positive.
+    pub fn determinant(&self) -> K::Real {
+        let mut result = K::Real::one();
+        for i in 0..self.size() {
+            let diagonal = self.l[(i, i)].re();
+            result = result * diagonal * diagonal;
+        }
+        result
+    }
+
+    /// Solves `Ly = b` then `Lᴴx = y` in place.
+    fn substitute(&self, mut b: Vec<K>) -> Vec<K> {
+        let size = self.size();
+        for row in 0..size {
+            for column in 0..row {
+                b[row] = b[row] - self.l[(row, column)] * b[column];
+            }
+            b[row] = b[row] / self.l[(row, row)];
+        }
+        for row in (0..size).rev() {
+            for column in row + 1..size {
+                b[row] = b[row] - self.l[(column, row)].conj() * b[column];
+            }
+            b[row] = b[row] / self.l[(row, row)];
+        }
+        b
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, LinalgError> {
+        if b.size() != self.size() {
+            return Err(LinalgError::ShapeMismatch {
+                left: (self.size(), self.size()),
+                right: (b.size(), 1),
+            });
+        }
+        Ok(Vector {
+            positions: self.substitute(b.positions.clone()),
+        })
+    }
+
+    pub fn solve_many(&self, b: &Matrix<K>) -> Result<Matrix<K>, LinalgError> {
+        let (rows, cols) = b.shape();
+        if rows != self.size() {
+            return Err(LinalgError::ShapeMismatch {
+                left: (self.size(), self.size()),
+                right: b.shape(),
+            });
+        }
+        let mut result = Matrix::zeros(rows, cols);
+        for column in 0..cols {
+            let solution = self.substitute(b.column(column).positions);
+            for (row, value) in solution.into_iter().enumerate() {
+                result[(row, column)] = value;
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn inverse(&self) -> Matrix<K> {
+        self.solve_many(&Matrix::identity(self.size()))
+            .expect("identity has the factor's size")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Complex;
+    use crate::{assert_approx_eq, Approx};
+
+    #[test]
+    fn cholesky_factor() {
+        let a = Matrix::from(&[&[4., 12., -16.], &[12., 37., -43.], &[-16., -43., 98.]]);
+        let cholesky = a.cholesky().unwrap();
+        let l = cholesky.l();
+        assert_eq!(
+            l,
+            &Matrix::from(&[&[2., 0., 0.], &[6., 1., 0.], &[-8., 5., 3.]])
+        );
+        assert_eq!(l * &l.conjugate_transpose(), a);
+        assert_eq!(cholesky.determinant(), 36.);
+    }
+
+    #[test]
+    fn cholesky_solve_and_inverse() {
+        let a: Matrix<f64> = Matrix::from(&[&[4., 2., 0.6], &[2., 5., 1.], &[0.6, 1., 3.]]);
+        let cholesky = a.cholesky().unwrap();
+        let x = Vector::from(&[1., -2., 0.5]);
+        let solution = cholesky.solve(&(&a * &x)).unwrap();
+        assert_approx_eq!(solution, x, Approx::Absolute(1e-12));
+        let b = Matrix::from(&[&[1., 0.], &[0., 1.], &[2., -1.]]);
+        assert_approx_eq!(
+            &a * &cholesky.solve_many(&b).unwrap(),
+            &b,
+            Approx::Absolute(1e-12)
+        );
+        assert_approx_eq!(
+            &a * &cholesky.inverse(),
+            &Matrix::identity(3),
+            Approx::Absolute(1e-12)
+        );
+        let determinant = a.clone().determinant();
+        assert!((cholesky.determinant() - determinant).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cholesky_hermitian() {
+        let a = Matrix::from(&[
+            &[Complex::new(2., 0.), Complex::new(0., -1.)],
+            &[Complex::new(0., 1.), Complex::new(2., 0.)],
+        ]);
+        let cholesky = a.cholesky().unwrap();
+        let l = cholesky.l();
+        let product = l * &l.conjugate_transpose();
+        for (x, y) in product.as_slice().iter().zip(a.as_slice()) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        assert!((cholesky.determinant() - 3.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cholesky_errors() {
+        let indefinite = Matrix::from(&[&[1., 2.], &[2., 1.]]);
+        assert_eq!(indefinite.cholesky(), Err(LinalgError::NotPositiveDefinite));
+        let semidefinite = Matrix::from(&[&[1., 1.], &[1., 1.]]);
+        assert_eq!(
+            semidefinite.cholesky(),
+            Err(LinalgError::NotPositiveDefinite)
+        );
+        assert_eq!(
+            Matrix::from(&[&[1., 0., 0.]]).cholesky(),
+            Err(LinalgError::NotSquare { shape: (1, 3) })
+        );
+        // only the lower triangle would be read without the symmetry check
+        let upper = Matrix::from(&[&[4., 100.], &[0., 4.]]);
+        assert_eq!(upper.cholesky(), Err(LinalgError::NotSymmetric));
+        let not_hermitian = Matrix::from(&[
+            &[Complex::new(2., 0.), Complex::new(0., 1.)],
+            &[Complex::new(0., 1.), Complex::new(2., 0.)],
+        ]);
+        assert_eq!(not_hermitian.cholesky(), Err(LinalgError::NotSymmetric));
+
+        let cholesky = Matrix::from(&[&[2.]]).cholesky().unwrap();
+        assert_eq!(
+            cholesky
+                .solve(&Vector::from(&[1., 1.]))
+                .map(|v| v.positions),
+            Err(LinalgError::ShapeMismatch {
+                left: (1, 1),
+                right: (2, 1)
+            })
+        );
+        assert_eq!(
+            LinalgError::NotPositiveDefinite.to_string(),
+            "matrix is not positive definite"
+        );
+    }
+}
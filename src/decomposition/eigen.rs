@@ -0,0 +1,582 @@
+use super::qr::Reflector;
+use crate::types::{Complex, Float, Matrix, Scalar, Vector};
+use crate::LinalgError;
+use std::cmp::Ordering;
+
+/// Sweeps `symmetric_eigen` allows before giving up.
+const DEFAULT_MAX_SWEEPS: usize = 100;
+
+/// QR iterations `eigenvalues` allows per eigenvalue before giving up.
+const MAX_QR_ITERATIONS: usize = 30;
+
+/// Every this many QR iterations without a split, `eigenvalues` takes an
+/// exceptional shift.
+const EXCEPTIONAL_SHIFT_PERIOD: usize = 10;
+
+/// Inverse iteration steps per eigenvector.
+const INVERSE_ITERATIONS: usize = 3;
+
+/// Eigen-decomposition `A = VΛVᵀ` of a real symmetric matrix.
+///
+/// Eigenvalues are sorted in ascending order and column `i` of `vectors()`
+/// is a unit eigenvector for `values()[i]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymmetricEigen<K> {
+    values: Vec<K>,
+    vectors: Matrix<K>,
+}
+
+impl<K: Float> Matrix<K> {
+    /// `symmetric_eigen_with` at machine precision and 100 sweeps.
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<K>, LinalgError> {
+        self.symmetric_eigen_with(K::epsilon(), DEFAULT_MAX_SWEEPS)
+    }
+
+    /// Cyclic Jacobi rotations, sweeping over every off-diagonal pair until
+    /// the off-diagonal norm is at most `tolerance` times the matrix norm.
+    ///
+    /// `tolerance` also bounds how far `A` may be from symmetric. Fails with
+    /// `NoConvergence` after `max_sweeps` sweeps.
+    pub fn symmetric_eigen_with(
+        &self,
+        tolerance: K,
+        max_sweeps: usize,
+    ) -> Result<SymmetricEigen<K>, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let size = self.shape().0;
+        let mut a = self.clone();
+        let mut vectors: Matrix<K> = Matrix::identity(size);
+
+        let mut norm = K::zero();
+        for &value in a.as_slice() {
+            norm = norm + value * value;
+        }
+        let threshold = tolerance * norm.sqrt();
+        for i in 0..size {
+            for j in 0..i {
+                if (a[(i, j)] - a[(j, i)]).abs() > threshold {
+                    return Err(LinalgError::NotSymmetric);
+                }
+            }
+        }
+
+        let mut sweeps = 0;
+        while off_diagonal_norm(&a) > threshold {
+            if sweeps == max_sweeps {
+                return Err(LinalgError::NoConvergence { iterations: sweeps });
+            }
+            for p in 0..size {
+                for q in p + 1..size {
+                    rotate(&mut a, &mut vectors, p, q);
+                }
+            }
+            sweeps += 1;
+        }
+
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|&i, &j| {
+            a[(i, i)]
+                .partial_cmp(&a[(j, j)])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let values = order.iter().map(|&i| a[(i, i)]).collect();
+        let mut sorted = Matrix::zeros(size, size);
+        for (column, &from) in order.iter().enumerate() {
+            for row in 0..size {
+                sorted[(row, column)] = vectors[(row, from)];
+            }
+        }
+        Ok(SymmetricEigen {
+            values,
+            vectors: sorted,
+        })
+    }
+}
+
+/// Eigenvalues and eigenvectors of a general real square matrix.
+///
+/// Eigenvalues are sorted by real then imaginary part, so complex conjugate
+/// pairs sit next to each other, and column `i` of `vectors()` is a unit
+/// eigenvector for `values()[i]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Eigen<K> {
+    values: Vec<Complex<K>>,
+    vectors: Matrix<Complex<K>>,
+}
+
+impl<K: Float> Matrix<K> {
+    /// Eigenvalues of a square matrix: Hessenberg reduction followed by
+    /// Francis double-shift QR iterations, so complex conjugate pairs are
+    /// found in real arithmetic.
+    ///
+    /// Fails with `NoConvergence` when an eigenvalue does not split off
+    /// within 30 iterations.
+    pub fn eigenvalues(&self) -> Result<Vec<Complex<K>>, LinalgError> {
+        let h = self.hessenberg()?.into_parts().1;
+        let mut values = francis_qr(&h)?;
+        values.sort_by(|a, b| {
+            a.re.partial_cmp(&b.re)
+                .unwrap_or(Ordering::Equal)
+                .then(a.im.partial_cmp(&b.im).unwrap_or(Ordering::Equal))
+        });
+        Ok(values)
+    }
+
+    /// `eigenvalues` together with eigenvectors found by inverse iteration
+    /// on `A - λI` in complex arithmetic.
+    ///
+    /// A repeated eigenvalue gets the same vector in each of its columns, so
+    /// `vectors()` only spans the eigenspaces of distinct eigenvalues.
+    pub fn eigen(&self) -> Result<Eigen<K>, LinalgError> {
+        let values = self.eigenvalues()?;
+        let size = self.shape().0;
+        let complex: Matrix<Complex<K>> = Matrix::from_vec(
+            size,
+            size,
+            self.as_slice().iter().map(|&x| Complex::from(x)).collect(),
+        )?;
+        let mut scale = K::one();
+        for &x in self.as_slice() {
+            if x.abs() > scale {
+                scale = x.abs();
+            }
+        }
+
+        let mut vectors = Matrix::zeros(size, size);
+        for (column, &value) in values.iter().enumerate() {
+            let vector = inverse_iteration(&complex, value, scale)?;
+            for (row, x) in vector.positions.into_iter().enumerate() {
+                vectors[(row, column)] = x;
+            }
+        }
+        Ok(Eigen { values, vectors })
+    }
+}
+
+/// Eigenvector for `value` by inverse iteration, shifting `value` just off
+/// the spectrum so that `A - μI` can be factorized.
+fn inverse_iteration<K: Float>(
+    a: &Matrix<Complex<K>>,
+    value: Complex<K>,
+    scale: K,
+) -> Result<Vector<Complex<K>>, LinalgError> {
+    let size = a.shape().0;
+    let mut offset = scale * K::epsilon();
+    let lu = loop {
+        let mut shifted = a.clone();
+        for i in 0..size {
+            shifted[(i, i)] = shifted[(i, i)] - value - Complex::from(offset);
+        }
+        let lu = shifted.lu()?;
+        if !lu.is_singular() {
+            break lu;
+        }
+        offset = offset * K::from_f64(1024.);
+    };
+
+    let mut vector = Vector {
+        positions: vec![Complex::one(); size],
+    };
+    for _ in 0..INVERSE_ITERATIONS {
+        vector = lu.solve(&vector)?;
+        let mut norm = K::zero();
+        let mut largest = Complex::zero();
+        for &x in vector.positions.iter() {
+            norm = norm + x.norm_sqr();
+            if x.modulus() > largest.modulus() {
+                largest = x;
+            }
+        }
+        // unit norm, with the largest component real and positive
+        let factor = largest
+            .conj()
+            .scale((norm.sqrt() * largest.modulus()).recip());
+        for x in vector.positions.iter_mut() {
+            *x = *x * factor;
+        }
+    }
+    Ok(vector)
+}
+
+/// Eigenvalues of an upper Hessenberg matrix by Francis double-shift QR
+/// steps with deflation, after Golub and Van Loan, Matrix Computations,
+/// §7.5 (Algorithm 7.5.1).
+///
+/// The active block `lo..=hi` shrinks from the bottom as its trailing 1 × 1
+/// or 2 × 2 blocks split off; every step is applied to the whole matrix, so
+/// it stays similar to `h`.
+fn francis_qr<K: Float>(h: &Matrix<K>) -> Result<Vec<Complex<K>>, LinalgError> {
+    let size = h.shape().0;
+    let mut a = h.clone();
+    let mut norm = K::zero();
+    for &value in a.as_slice() {
+        norm = norm + value.abs();
+    }
+
+    let mut values = Vec::with_capacity(size);
+    let mut end = size;
+    let mut iterations = 0;
+    while end > 0 {
+        let hi = end - 1;
+        let mut lo = hi;
+        while lo > 0 {
+            let mut scale = a[(lo - 1, lo - 1)].abs() + a[(lo, lo)].abs();
+            if scale.is_zero() {
+                scale = norm;
+            }
+            if a[(lo, lo - 1)].abs() <= K::epsilon() * scale {
+                a[(lo, lo - 1)] = K::zero();
+                break;
+            }
+            lo -= 1;
+        }
+
+        if lo == hi {
+            values.push(Complex::from(a[(hi, hi)]));
+            end -= 1;
+            iterations = 0;
+        } else if lo + 1 == hi {
+            let (first, second) =
+                block_eigenvalues(a[(lo, lo)], a[(lo, hi)], a[(hi, lo)], a[(hi, hi)]);
+            values.push(first);
+            values.push(second);
+            end -= 2;
+            iterations = 0;
+        } else {
+            if iterations == MAX_QR_ITERATIONS {
+                return Err(LinalgError::NoConvergence { iterations });
+            }
+            iterations += 1;
+            francis_step(&mut a, lo, hi, iterations % EXCEPTIONAL_SHIFT_PERIOD == 0);
+        }
+    }
+    Ok(values)
+}
+
+/// One implicit double-shift step on the block `lo..=hi`, at least 3 × 3.
+///
+/// The shifts are the eigenvalues of the trailing 2 × 2 block, entering
+/// only through their sum and product. An `exceptional` step instead uses
+/// the real double shift `h[hi][hi] + |h[hi][hi - 1]|` to break the cycles
+/// the Francis shifts can fall into.
+fn francis_step<K: Float>(a: &mut Matrix<K>, lo: usize, hi: usize, exceptional: bool) {
+    let (sum, product) = if exceptional {
+        let shift = a[(hi, hi)] + a[(hi, hi - 1)].abs();
+        (shift + shift, shift * shift)
+    } else {
+        (
+            a[(hi - 1, hi - 1)] + a[(hi, hi)],
+            a[(hi - 1, hi - 1)] * a[(hi, hi)] - a[(hi - 1, hi)] * a[(hi, hi - 1)],
+        )
+    };
+    // first column of (H - μ₁I)(H - μ₂I), three entries long
+    let mut x =
+        a[(lo, lo)] * a[(lo, lo)] + a[(lo, lo + 1)] * a[(lo + 1, lo)] - sum * a[(lo, lo)] + product;
+    let mut y = a[(lo + 1, lo)] * (a[(lo, lo)] + a[(lo + 1, lo + 1)] - sum);
+    let mut z = a[(lo + 1, lo)] * a[(lo + 2, lo + 1)];
+
+    // chase the bulge down the subdiagonal
+    for k in lo..hi - 1 {
+        let first_column = if k == lo { lo } else { k - 1 };
+        if let Some(reflector) = Reflector::new(k, vec![x, y, z]) {
+            reflector.apply(a, first_column);
+            reflector.apply_right(a, (k + 4).min(hi + 1));
+        }
+        if k > lo {
+            a[(k + 1, k - 1)] = K::zero();
+            a[(k + 2, k - 1)] = K::zero();
+        }
+        x = a[(k + 1, k)];
+        y = a[(k + 2, k)];
+        if k + 3 <= hi {
+            z = a[(k + 3, k)];
+        }
+    }
+    if let Some(reflector) = Reflector::new(hi - 1, vec![x, y]) {
+        reflector.apply(a, hi - 2);
+        reflector.apply_right(a, hi + 1);
+    }
+    a[(hi, hi - 2)] = K::zero();
+}
+
+/// Eigenvalues of `[[a, b], [c, d]]`, a conjugate pair when complex.
+fn block_eigenvalues<K: Float>(a: K, b: K, c: K, d: K) -> (Complex<K>, Complex<K>) {
+    let half = K::from_f64(0.5);
+    let p = half * (a - d);
+    let discriminant = p * p + b * c;
+    let root = discriminant.abs().sqrt();
+    if discriminant >= K::zero() {
+        // d + z and its partner through the product, without cancellation
+        let z = if p >= K::zero() { p + root } else { p - root };
+        let second = if z.is_zero() { d } else { d - b * c / z };
+        (Complex::from(d + z), Complex::from(second))
+    } else {
+        let mean = half * (a + d);
+        (Complex::new(mean, -root), Complex::new(mean, root))
+    }
+}
+
+fn off_diagonal_norm<K: Float>(a: &Matrix<K>) -> K {
+    let size = a.shape().0;
+    let mut result = K::zero();
+    for i in 0..size {
+        for j in 0..size {
+            if i != j {
+                result = result + a[(i, j)] * a[(i, j)];
+            }
+        }
+    }
+    result.sqrt()
+}
+
+/// Applies the rotation `J` in the `(p, q)` plane that zeroes `a[(p, q)]`:
+/// `a ← JᵀaJ` and `vectors ← vectors·J`.
+fn rotate<K: Float>(a: &mut Matrix<K>, vectors: &mut Matrix<K>, p: usize, q: usize) {
+    if a[(p, q)].is_zero() {
+        return;
+    }
+    let size = a.shape().0;
+    let two = K::one() + K::one();
+    let theta = (a[(q, q)] - a[(p, p)]) / (two * a[(p, q)]);
+    // smaller root of t² + 2θt - 1 = 0, keeping the rotation below π/4
+    let t = (theta * theta + K::one()).sqrt() + theta.abs();
+    let t = if theta < K::zero() {
+        -t.recip()
+    } else {
+        t.recip()
+    };
+    let c = (t * t + K::one()).sqrt().recip();
+    let s = t * c;
+
+    for k in 0..size {
+        let (kp, kq) = (a[(k, p)], a[(k, q)]);
+        a[(k, p)] = c * kp - s * kq;
+        a[(k, q)] = s * kp + c * kq;
+    }
+    for k in 0..size {
+        let (pk, qk) = (a[(p, k)], a[(q, k)]);
+        a[(p, k)] = c * pk - s * qk;
+        a[(q, k)] = s * pk + c * qk;
+    }
+    for k in 0..size {
+        let (kp, kq) = (vectors[(k, p)], vectors[(k, q)]);
+        vectors[(k, p)] = c * kp - s * kq;
+        vectors[(k, q)] = s * kp + c * kq;
+    }
+}
+
+impl<K: Float> SymmetricEigen<K> {
+    pub fn values(&self) -> &[K] {
+        &self.values
+    }
+
+    pub fn vectors(&self) -> &Matrix<K> {
+        &self.vectors
+    }
+}
+
+impl<K: Float> Eigen<K> {
+    pub fn values(&self) -> &[Complex<K>] {
+        &self.values
+    }
+
+    pub fn vectors(&self) -> &Matrix<Complex<K>> {
+        &self.vectors
+    }
+
+    /// Whether every eigenvalue has a negative real part, i.e. `ẋ = Ax` is
+    /// asymptotically stable.
+    pub fn is_stable(&self) -> bool {
+        self.values.iter().all(|value| value.re < K::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_approx_eq, Approx};
+
+    fn check(a: &Matrix<f64>, eigen: &SymmetricEigen<f64>) {
+        let v = eigen.vectors();
+        let size = a.shape().0;
+        assert_approx_eq!(
+            &v.conjugate_transpose() * v,
+            &Matrix::identity(size),
+            Approx::Absolute(1e-10)
+        );
+        let mut lambda = Matrix::zeros(size, size);
+        for (i, &value) in eigen.values().iter().enumerate() {
+            lambda[(i, i)] = value;
+        }
+        assert_approx_eq!(
+            &(v * &lambda) * &v.conjugate_transpose(),
+            a,
+            Approx::Absolute(1e-10)
+        );
+        assert!(eigen.values().windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn symmetric_eigen_values() {
+        let a: Matrix<f64> = Matrix::from(&[&[2., 1.], &[1., 2.]]);
+        let eigen = a.symmetric_eigen().unwrap();
+        assert!((eigen.values()[0] - 1.).abs() < 1e-12);
+        assert!((eigen.values()[1] - 3.).abs() < 1e-12);
+        check(&a, &eigen);
+
+        let a = Matrix::from(&[
+            &[4., -2., 2., 1.],
+            &[-2., 6., 0., -1.],
+            &[2., 0., 5., 3.],
+            &[1., -1., 3., 7.],
+        ]);
+        let eigen = a.symmetric_eigen().unwrap();
+        check(&a, &eigen);
+        let trace: f64 = eigen.values().iter().sum();
+        assert!((trace - 22.).abs() < 1e-10);
+
+        let diagonal = Matrix::from(&[&[3., 0., 0.], &[0., -1., 0.], &[0., 0., 2.]]);
+        let eigen = diagonal.symmetric_eigen().unwrap();
+        assert_eq!(eigen.values(), [-1., 2., 3.]);
+        check(&diagonal, &eigen);
+    }
+
+    #[test]
+    fn symmetric_eigen_f32() {
+        let a: Matrix<f32> = Matrix::from(&[&[1., 2., 0.], &[2., 1., 0.], &[0., 0., 5.]]);
+        let eigen = a.symmetric_eigen().unwrap();
+        for (value, expected) in eigen.values().iter().zip([-1., 3., 5.]) {
+            assert!((value - expected).abs() < 1e-5);
+        }
+    }
+
+    fn check_general(a: &Matrix<f64>, eigen: &Eigen<f64>) {
+        let size = a.shape().0;
+        for (column, &value) in eigen.values().iter().enumerate() {
+            let v = eigen.vectors().column(column);
+            let mut norm = 0.;
+            for row in 0..size {
+                let mut product = Complex::zero();
+                for k in 0..size {
+                    product = product + Complex::from(a[(row, k)]) * v.positions[k];
+                }
+                let residual = product - value * v.positions[row];
+                assert!(residual.modulus() < 1e-9, "{value}: {residual}");
+                norm += v.positions[row].norm_sqr();
+            }
+            assert!((norm - 1.).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn general_eigenvalues() {
+        let rotation = Matrix::from(&[&[0., -1.], &[1., 0.]]);
+        let eigen = rotation.eigen().unwrap();
+        assert_eq!(
+            eigen.values(),
+            [Complex::new(0., -1.), Complex::new(0., 1.)]
+        );
+        check_general(&rotation, &eigen);
+        assert!(!eigen.is_stable());
+
+        let triangular = Matrix::from(&[&[1., 2., 3.], &[0., 4., 5.], &[0., 0., 6.]]);
+        let values = triangular.eigenvalues().unwrap();
+        for (value, expected) in values.iter().zip([1., 4., 6.]) {
+            assert!((value.re - expected).abs() < 1e-12 && value.im == 0.);
+        }
+        check_general(&triangular, &triangular.eigen().unwrap());
+
+        // companion matrix of (x² + 1)(x - 2)(x + 3)
+        let companion = Matrix::from(&[
+            &[0., 0., 0., 6.],
+            &[1., 0., 0., -1.],
+            &[0., 1., 0., 5.],
+            &[0., 0., 1., -1.],
+        ]);
+        let eigen = companion.eigen().unwrap();
+        let expected = [(-3., 0.), (0., -1.), (0., 1.), (2., 0.)];
+        for (value, (re, im)) in eigen.values().iter().zip(expected) {
+            assert!((value.re - re).abs() < 1e-10 && (value.im - im).abs() < 1e-10);
+        }
+        check_general(&companion, &eigen);
+    }
+
+    #[test]
+    fn general_eigen_stability() {
+        // damped oscillator ẍ + 0.5ẋ + 4x = 0
+        let damped = Matrix::from(&[&[0., 1.], &[-4., -0.5]]);
+        let eigen = damped.eigen().unwrap();
+        assert!(eigen.is_stable());
+        assert_eq!(eigen.values()[0], eigen.values()[1].conj());
+        check_general(&damped, &eigen);
+
+        let a = Matrix::from(&[
+            &[1., 2., 0., -1., 3.],
+            &[0., -1., 4., 2., 1.],
+            &[2., 1., 1., 0., -2.],
+            &[-1., 3., 0., 2., 1.],
+            &[1., 0., -2., 1., 0.],
+        ]);
+        let eigen = a.eigen().unwrap();
+        check_general(&a, &eigen);
+        let trace: f64 = eigen.values().iter().map(|value| value.re).sum();
+        assert!((trace - 3.).abs() < 1e-10);
+
+        let a: Matrix<f32> = Matrix::from(&[&[2., 1.], &[1., 2.]]);
+        let values = a.eigenvalues().unwrap();
+        assert!((values[0].re - 1.).abs() < 1e-5 && (values[1].re - 3.).abs() < 1e-5);
+        assert_eq!(
+            Matrix::from(&[&[1., 2.]]).eigenvalues(),
+            Err(LinalgError::NotSquare { shape: (1, 2) })
+        );
+    }
+
+    #[test]
+    fn general_eigen_cyclic() {
+        // the Francis shifts of a cyclic permutation are all zero, so only
+        // the exceptional shifts make progress; its eigenvalues are the
+        // fifth roots of unity
+        let size = 5;
+        let mut cyclic: Matrix<f64> = Matrix::zeros(size, size);
+        for i in 0..size {
+            cyclic[((i + 1) % size, i)] = 1.;
+        }
+        let eigen = cyclic.eigen().unwrap();
+        check_general(&cyclic, &eigen);
+        for value in eigen.values() {
+            assert!((value.modulus() - 1.).abs() < 1e-10);
+        }
+
+        let size = 12;
+        let mut a: Matrix<f64> = Matrix::zeros(size, size);
+        for i in 0..size {
+            for j in 0..size {
+                a[(i, j)] = ((i * 7 + j * 3) % 11) as f64 - 5.;
+            }
+        }
+        let values = a.eigenvalues().unwrap();
+        let trace: f64 = values.iter().map(|value| value.re).sum();
+        let expected: f64 = (0..size).map(|i| a[(i, i)]).sum();
+        assert!((trace - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn symmetric_eigen_errors() {
+        let a = Matrix::from(&[&[1., 2.], &[0., 1.]]);
+        assert_eq!(a.symmetric_eigen(), Err(LinalgError::NotSymmetric));
+        assert_eq!(
+            Matrix::from(&[&[1., 2.]]).symmetric_eigen(),
+            Err(LinalgError::NotSquare { shape: (1, 2) })
+        );
+        let a = Matrix::from(&[&[4., -2., 2.], &[-2., 6., 0.], &[2., 0., 5.]]);
+        assert_eq!(
+            a.symmetric_eigen_with(1e-15, 0),
+            Err(LinalgError::NoConvergence { iterations: 0 })
+        );
+        assert!(a.symmetric_eigen_with(1e-6, 100).is_ok());
+    }
+}
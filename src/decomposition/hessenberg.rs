@@ -0,0 +1,130 @@
+use crate::types::{Float, Matrix};
+use crate::LinalgError;
+
+/// `A = QHQᵀ` reduction of a square matrix to upper Hessenberg form, with `Q`
+/// orthogonal and `H` zero below its first subdiagonal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hessenberg<K> {
+    q: Matrix<K>,
+    h: Matrix<K>,
+}
+
+impl<K: Float> Matrix<K> {
+    /// Householder reduction, reflecting away column `k` below the
+    /// subdiagonal for every `k`.
+    pub fn hessenberg(&self) -> Result<Hessenberg<K>, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let size = self.shape().0;
+        let mut q: Matrix<K> = Matrix::identity(size);
+        let mut h = self.clone();
+        let two = K::one() + K::one();
+
+        for k in 0..size.saturating_sub(2) {
+            let mut norm = K::zero();
+            for row in k + 1..size {
+                norm = norm + h[(row, k)] * h[(row, k)];
+            }
+            let norm = norm.sqrt();
+            if norm.is_zero() {
+                continue;
+            }
+            let alpha = if h[(k + 1, k)] > K::zero() {
+                -norm
+            } else {
+                norm
+            };
+            let mut v: Vec<K> = (k + 1..size).map(|row| h[(row, k)]).collect();
+            v[0] = v[0] - alpha;
+            let v_norm = v.iter().fold(K::zero(), |acc, &x| acc + x * x);
+            if v_norm.is_zero() {
+                continue;
+            }
+
+            // H ← PH
+            for column in 0..size {
+                let mut projection = K::zero();
+                for (i, &vi) in v.iter().enumerate() {
+                    projection = projection + vi * h[(k + 1 + i, column)];
+                }
+                let factor = two * projection / v_norm;
+                for (i, &vi) in v.iter().enumerate() {
+                    h[(k + 1 + i, column)] = h[(k + 1 + i, column)] - factor * vi;
+                }
+            }
+            // H ← HP and Q ← QP
+            for target in [&mut h, &mut q] {
+                for row in 0..size {
+                    let mut projection = K::zero();
+                    for (i, &vi) in v.iter().enumerate() {
+                        projection = projection + target[(row, k + 1 + i)] * vi;
+                    }
+                    let factor = two * projection / v_norm;
+                    for (i, &vi) in v.iter().enumerate() {
+                        target[(row, k + 1 + i)] = target[(row, k + 1 + i)] - factor * vi;
+                    }
+                }
+            }
+            for row in k + 2..size {
+                h[(row, k)] = K::zero();
+            }
+        }
+        Ok(Hessenberg { q, h })
+    }
+}
+
+impl<K: Float> Hessenberg<K> {
+    pub fn q(&self) -> &Matrix<K> {
+        &self.q
+    }
+
+    pub fn h(&self) -> &Matrix<K> {
+        &self.h
+    }
+
+    pub fn into_parts(self) -> (Matrix<K>, Matrix<K>) {
+        (self.q, self.h)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_approx_eq, Approx};
+
+    #[test]
+    fn hessenberg_form() {
+        let a = Matrix::from(&[
+            &[4., 1., -2., 2.],
+            &[1., 2., 0., 1.],
+            &[-2., 0., 3., -2.],
+            &[2., 1., -2., -1.],
+        ]);
+        let (q, h) = a.hessenberg().unwrap().into_parts();
+        assert_approx_eq!(
+            &q.conjugate_transpose() * &q,
+            &Matrix::identity(4),
+            Approx::Absolute(1e-12)
+        );
+        assert_approx_eq!(
+            &(&q * &h) * &q.conjugate_transpose(),
+            &a,
+            Approx::Absolute(1e-12)
+        );
+        for row in 2..4 {
+            for column in 0..row - 1 {
+                assert_eq!(h[(row, column)], 0.);
+            }
+        }
+
+        let small = Matrix::from(&[&[1., 2.], &[3., 4.]]);
+        assert_eq!(small.hessenberg().unwrap().h(), &small);
+        assert_eq!(
+            Matrix::from(&[&[1., 2.]]).hessenberg(),
+            Err(LinalgError::NotSquare { shape: (1, 2) })
+        );
+    }
+}
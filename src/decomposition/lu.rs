@@ -0,0 +1,304 @@
+use crate::types::{Field, Matrix, Tolerance, Vector};
+use crate::LinalgError;
+
+/// `PA = LU` factorization of a square matrix, computed once by Gaussian
+/// elimination with partial pivoting and reusable for determinants, solves
+/// and the inverse.
+///
+/// `L` is unit lower triangular, `U` upper triangular and row `i` of `PA`
+/// is row `permutation()[i]` of `A`. A singular matrix still factorizes,
+/// with a negligible pivot on the diagonal of `U`; only the solves then
+/// fail.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lu<K> {
+    l: Matrix<K>,
+    u: Matrix<K>,
+    permutation: Vec<usize>,
+    sign: K,
+    singular: bool,
+}
+
+impl<K: Field> Matrix<K> {
+    /// `lu_with` at the default tolerance.
+    pub fn lu(&self) -> Result<Lu<K>, LinalgError> {
+        self.lu_with(Tolerance::default_for(self.shape()))
+    }
+
+    /// A pivot within `tolerance` of zero marks the matrix as singular; the
+    /// factorization still completes.
+    pub fn lu_with(&self, tolerance: Tolerance<K::Real>) -> Result<Lu<K>, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let threshold = tolerance.threshold(self);
+        let mut singular = false;
+        let size = self.shape().0;
+        let mut l: Matrix<K> = Matrix::identity(size);
+        let mut u = self.clone();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut sign = K::one();
+
+        for k in 0..size {
+            let mut pivot_row = k;
+            for row in k + 1..size {
+                if u[(row, k)].abs() > u[(pivot_row, k)].abs() {
+                    pivot_row = row;
+                }
+            }
+            if u[(pivot_row, k)].abs() <= threshold {
+                singular = true;
+            }
+            if u[(pivot_row, k)].is_zero() {
+                continue;
+            }
+            if pivot_row != k {
+                for column in 0..size {
+                    let tmp = u[(k, column)];
+                    u[(k, column)] = u[(pivot_row, column)];
+                    u[(pivot_row, column)] = tmp;
+                }
+                for column in 0..k {
+                    let tmp = l[(k, column)];
+                    l[(k, column)] = l[(pivot_row, column)];
+                    l[(pivot_row, column)] = tmp;
+                }
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+            for row in k + 1..size {
+                let factor = u[(row, k)] / u[(k, k)];
+                l[(row, k)] = factor;
+                u[(row, k)] = K::zero();
+                for column in k + 1..size {
+                    u[(row, column)] = u[(row, column)] - factor * u[(k, column)];
+                }
+            }
+        }
+        Ok(Lu {
+            l,
+            u,
+            permutation,
+            sign,
+            singular,
+        })
+    }
+}
+
+impl<K: Field> Lu<K> {
+    pub fn l(&self) -> &Matrix<K> {
+        &self.l
+    }
+
+    pub fn u(&self) -> &Matrix<K> {
+        &self.u
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn size(&self) -> usize {
+        self.permutation.len()
+    }
+
+    /// Whether some pivot was within the factorization tolerance of zero.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// `±` the product of the diagonal of `U`, the sign set by the row swaps.
+    pub fn determinant(&self) -> K {
+        let mut result = self.sign;
+        for i in 0..self.size() {
+            result = result * self.u[(i, i)];
+        }
+        result
+    }
+
+    /// Forward then back substitution on `b`, already permuted.
+    fn substitute(&self, mut b: Vec<K>) -> Vec<K> {
+        let size = self.size();
+        for row in 0..size {
+            for column in 0..row {
+                b[row] = b[row] - self.l[(row, column)] * b[column];
+            }
+        }
+        for row in (0..size).rev() {
+            for column in row + 1..size {
+                b[row] = b[row] - self.u[(row, column)] * b[column];
+            }
+            b[row] = b[row] / self.u[(row, row)];
+        }
+        b
+    }
+
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, LinalgError> {
+        if b.size() != self.size() {
+            return Err(LinalgError::ShapeMismatch {
+                left: (self.size(), self.size()),
+                right: (b.size(), 1),
+            });
+        }
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        let permuted = self.permutation.iter().map(|&i| b.positions[i]).collect();
+        Ok(Vector {
+            positions: self.substitute(permuted),
+        })
+    }
+
+    /// Solves `AX = B` column by column, reusing the factorization.
+    pub fn solve_many(&self, b: &Matrix<K>) -> Result<Matrix<K>, LinalgError> {
+        let (rows, cols) = b.shape();
+        if rows != self.size() {
+            return Err(LinalgError::ShapeMismatch {
+                left: (self.size(), self.size()),
+                right: b.shape(),
+            });
+        }
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        let mut result = Matrix::zeros(rows, cols);
+        for column in 0..cols {
+            let permuted = self.permutation.iter().map(|&i| b[(i, column)]).collect();
+            for (row, value) in self.substitute(permuted).into_iter().enumerate() {
+                result[(row, column)] = value;
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<K>, LinalgError> {
+        self.solve_many(&Matrix::identity(self.size()))
+    }
+
+    /// Solves `Aᴴx = b` with the same factors: `Aᴴ = UᴴLᴴP`, so forward
+    /// substitution with `Uᴴ`, back substitution with `Lᴴ`, then `Pᵀ`.
+    pub fn solve_adjoint(&self, b: &Vector<K>) -> Result<Vector<K>, LinalgError> {
+        let size = self.size();
+        if b.size() != size {
+            return Err(LinalgError::ShapeMismatch {
+                left: (size, size),
+                right: (b.size(), 1),
+            });
+        }
+        if self.is_singular() {
+            return Err(LinalgError::Singular);
+        }
+        let mut z = b.positions.clone();
+        for row in 0..size {
+            for column in 0..row {
+                z[row] = z[row] - self.u[(column, row)].conj() * z[column];
+            }
+            z[row] = z[row] / self.u[(row, row)].conj();
+        }
+        for row in (0..size).rev() {
+            for column in row + 1..size {
+                z[row] = z[row] - self.l[(column, row)].conj() * z[column];
+            }
+        }
+        let mut positions = vec![K::zero(); size];
+        for (i, &original) in self.permutation.iter().enumerate() {
+            positions[original] = z[i];
+        }
+        Ok(Vector { positions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rational, Scalar};
+
+    #[test]
+    fn lu_factors() {
+        let r = Rational::from;
+        let a = Matrix::from(&[
+            &[r(1), r(2), r(0)],
+            &[r(3), r(4), r(4)],
+            &[r(5), r(6), r(3)],
+        ]);
+        let lu = a.lu().unwrap();
+        assert_eq!(lu.permutation(), [2, 0, 1]);
+
+        let mut permuted: Matrix<Rational> = Matrix::zeros(3, 3);
+        for (row, &from) in lu.permutation().iter().enumerate() {
+            permuted.row_mut(row).copy_from_slice(a.row(from));
+        }
+        assert_eq!(lu.l() * lu.u(), permuted);
+        for i in 0..3 {
+            assert_eq!(lu.l()[(i, i)], Rational::one());
+            for j in 0..i {
+                assert_eq!(lu.u()[(i, j)], Rational::zero());
+            }
+        }
+        assert_eq!(lu.determinant(), r(10));
+        assert_eq!(lu.determinant(), a.clone().determinant());
+    }
+
+    #[test]
+    fn lu_solve_and_inverse() {
+        let r = Rational::from;
+        let a = Matrix::from(&[
+            &[r(2), r(1), r(1)],
+            &[r(4), r(-6), r(0)],
+            &[r(-2), r(7), r(2)],
+        ]);
+        let lu = a.lu().unwrap();
+        let x = Matrix::from(&[&[r(1), r(2)], &[r(1), r(0)], &[r(2), r(-1)]]);
+        let b = &a * &x;
+        assert_eq!(lu.solve_many(&b), Ok(x.clone()));
+        assert_eq!(
+            lu.solve(&b.column(0)).map(|v| v.positions),
+            Ok(x.column(0).positions)
+        );
+        let inverse = lu.inverse().unwrap();
+        assert_eq!(&a * &inverse, Matrix::identity(3));
+        let adjoint = a.conjugate_transpose();
+        assert_eq!(
+            lu.solve_adjoint(&adjoint.try_mul_vec(&x.column(1)).unwrap())
+                .map(|v| v.positions),
+            Ok(x.column(1).positions)
+        );
+
+        let lu = Matrix::from(&[&[4., 3.], &[6., 3.]]).lu().unwrap();
+        assert_eq!(lu.determinant(), -6.);
+        assert_eq!(
+            lu.solve(&Vector::from(&[10., 12.])).map(|v| v.positions),
+            Ok(vec![1., 2.])
+        );
+    }
+
+    #[test]
+    fn lu_errors() {
+        let a = Matrix::from(&[&[1., 2.], &[2., 4.]]);
+        let lu = a.lu().unwrap();
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(), 0.);
+        assert_eq!(
+            lu.solve(&Vector::from(&[1., 2.])).map(|v| v.positions),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(lu.inverse(), Err(LinalgError::Singular));
+        assert_eq!(
+            lu.solve(&Vector::from(&[1.])).map(|v| v.positions),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 2),
+                right: (1, 1)
+            })
+        );
+        assert_eq!(
+            Matrix::from(&[&[1., 2.]]).lu(),
+            Err(LinalgError::NotSquare { shape: (1, 2) })
+        );
+
+        let empty: Matrix<f64> = Matrix::zeros(0, 0);
+        let lu = empty.lu().unwrap();
+        assert_eq!(lu.determinant(), 1.);
+        assert_eq!(lu.inverse(), Ok(empty));
+    }
+}
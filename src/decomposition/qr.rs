@@ -0,0 +1,211 @@
+use crate::types::{Float, Matrix};
+
+/// `A = QR` factorization with `Q` orthogonal and `R` upper triangular.
+///
+/// For an `m × n` matrix the full form has an `m × m` `Q` and an `m × n`
+/// `R`; the thin form keeps only the first `min(m, n)` columns of `Q` and
+/// rows of `R`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Qr<K> {
+    q: Matrix<K>,
+    r: Matrix<K>,
+}
+
+/// A Householder reflector `I − 2vvᵀ/‖v‖²` acting on rows `offset..`.
+pub(super) struct Reflector<K> {
+    offset: usize,
+    v: Vec<K>,
+    v_norm: K,
+}
+
+impl<K: Float> Reflector<K> {
+    /// The reflector taking `x`, placed at `offset`, onto a multiple of the
+    /// first basis vector, or `None` when `x` is already zero.
+    pub(super) fn new(offset: usize, x: Vec<K>) -> Option<Self> {
+        let norm = x.iter().fold(K::zero(), |acc, &xi| acc + xi * xi).sqrt();
+        if norm.is_zero() {
+            return None;
+        }
+        // reflect onto -sign(x0)·‖x‖·e0 to avoid cancellation in v0
+        let alpha = if x[0] > K::zero() { -norm } else { norm };
+        let mut v = x;
+        v[0] = v[0] - alpha;
+        let v_norm = v.iter().fold(K::zero(), |acc, &vi| acc + vi * vi);
+        if v_norm.is_zero() {
+            return None;
+        }
+        Some(Reflector { offset, v, v_norm })
+    }
+
+    /// Reflects the columns of `matrix` from `first_column` on, in place.
+    pub(super) fn apply(&self, matrix: &mut Matrix<K>, first_column: usize) {
+        let two = K::one() + K::one();
+        for column in first_column..matrix.shape().1 {
+            let mut projection = K::zero();
+            for (i, &vi) in self.v.iter().enumerate() {
+                projection = projection + vi * matrix[(self.offset + i, column)];
+            }
+            let factor = two * projection / self.v_norm;
+            for (i, &vi) in self.v.iter().enumerate() {
+                matrix[(self.offset + i, column)] = matrix[(self.offset + i, column)] - factor * vi;
+            }
+        }
+    }
+
+    /// Reflects the first `rows` rows of `matrix` from the right, in place.
+    pub(super) fn apply_right(&self, matrix: &mut Matrix<K>, rows: usize) {
+        let two = K::one() + K::one();
+        for row in 0..rows {
+            let mut projection = K::zero();
+            for (i, &vi) in self.v.iter().enumerate() {
+                projection = projection + matrix[(row, self.offset + i)] * vi;
+            }
+            let factor = two * projection / self.v_norm;
+            for (i, &vi) in self.v.iter().enumerate() {
+                matrix[(row, self.offset + i)] = matrix[(row, self.offset + i)] - factor * vi;
+            }
+        }
+    }
+}
+
+impl<K: Float> Matrix<K> {
+    /// Reduces `A` to `R` by Householder reflections, returning `R` and the
+    /// reflectors in the order they were applied.
+    fn householder(&self) -> (Vec<Reflector<K>>, Matrix<K>) {
+        let (rows, cols) = self.shape();
+        let mut r = self.clone();
+        let mut reflectors = Vec::new();
+
+        for k in 0..cols.min(rows.saturating_sub(1)) {
+            let x = (k..rows).map(|row| r[(row, k)]).collect();
+            let Some(reflector) = Reflector::new(k, x) else {
+                continue;
+            };
+            reflector.apply(&mut r, k);
+            for row in k + 1..rows {
+                r[(row, k)] = K::zero();
+            }
+            reflectors.push(reflector);
+        }
+        (reflectors, r)
+    }
+
+    /// The first `columns` columns of `Q = H₀H₁⋯`, applying the reflectors
+    /// right to left to those columns of the identity only.
+    fn q_columns(reflectors: &[Reflector<K>], rows: usize, columns: usize) -> Matrix<K> {
+        let mut q = Matrix::zeros(rows, columns);
+        for i in 0..columns {
+            q[(i, i)] = K::one();
+        }
+        for reflector in reflectors.iter().rev() {
+            reflector.apply(&mut q, 0);
+        }
+        q
+    }
+
+    /// Full QR factorization by Householder reflections.
+    pub fn qr(&self) -> Qr<K> {
+        let rows = self.shape().0;
+        let (reflectors, r) = self.householder();
+        let q = Matrix::q_columns(&reflectors, rows, rows);
+        Qr { q, r }
+    }
+
+    /// Thin QR factorization: `Q` is `m × min(m, n)` with orthonormal
+    /// columns and `R` is `min(m, n) × n`. Never forms the `m × m` `Q`, so
+    /// a tall `A` costs `O(mn)` memory.
+    pub fn thin_qr(&self) -> Qr<K> {
+        let (rows, cols) = self.shape();
+        let size = rows.min(cols);
+        let (reflectors, full_r) = self.householder();
+        let q = Matrix::q_columns(&reflectors, rows, size);
+        let mut r = Matrix::zeros(size, cols);
+        for row in 0..size {
+            r.row_mut(row).copy_from_slice(full_r.row(row));
+        }
+        Qr { q, r }
+    }
+}
+
+impl<K: Float> Qr<K> {
+    pub fn q(&self) -> &Matrix<K> {
+        &self.q
+    }
+
+    pub fn r(&self) -> &Matrix<K> {
+        &self.r
+    }
+
+    pub fn into_parts(self) -> (Matrix<K>, Matrix<K>) {
+        (self.q, self.r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_approx_eq, Approx};
+
+    fn check(a: &Matrix<f64>, qr: &Qr<f64>) {
+        let (q, r) = (qr.q(), qr.r());
+        let columns = q.shape().1;
+        assert_approx_eq!(
+            &q.conjugate_transpose() * q,
+            &Matrix::identity(columns),
+            Approx::Absolute(1e-12)
+        );
+        assert_approx_eq!(q * r, a, Approx::Absolute(1e-12));
+        for row in 0..r.shape().0 {
+            for column in 0..row.min(r.shape().1) {
+                assert_eq!(r[(row, column)], 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn qr_square() {
+        let a = Matrix::from(&[&[12., -51., 4.], &[6., 167., -68.], &[-4., 24., -41.]]);
+        let qr = a.qr();
+        check(&a, &qr);
+        assert!((qr.r()[(0, 0)].abs() - 14.).abs() < 1e-12);
+        assert_eq!(a.thin_qr(), qr);
+    }
+
+    #[test]
+    fn qr_rectangular() {
+        let tall = Matrix::from(&[&[1., 2.], &[3., 4.], &[5., 6.], &[7., 8.]]);
+        let full = tall.qr();
+        assert_eq!(full.q().shape(), (4, 4));
+        assert_eq!(full.r().shape(), (4, 2));
+        check(&tall, &full);
+        let thin = tall.thin_qr();
+        assert_eq!(thin.q().shape(), (4, 2));
+        assert_eq!(thin.r().shape(), (2, 2));
+        check(&tall, &thin);
+
+        let wide = Matrix::from(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        check(&wide, &wide.qr());
+        check(&wide, &wide.thin_qr());
+
+        // rank deficient input still factorizes
+        let singular = Matrix::from(&[&[1., 2.], &[2., 4.], &[0., 0.]]);
+        check(&singular, &singular.qr());
+
+        // the thin Q of a long tall matrix is only m × n
+        let positions = (0..1000).flat_map(|i| [1., i as f64 / 1000.]).collect();
+        let long = Matrix::from_vec(1000, 2, positions).unwrap();
+        let thin = long.thin_qr();
+        assert_eq!(thin.q().shape(), (1000, 2));
+        check(&long, &thin);
+    }
+
+    #[test]
+    fn qr_f32() {
+        let a: Matrix<f32> = Matrix::from(&[&[2., -1.], &[1., 3.]]);
+        let (q, r) = a.qr().into_parts();
+        let product = &q * &r;
+        for (x, y) in product.as_slice().iter().zip(a.as_slice()) {
+            assert!((x - y).abs() < 1e-5);
+        }
+    }
+}
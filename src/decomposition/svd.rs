@@ -0,0 +1,432 @@
+use crate::types::{Float, Matrix};
+use crate::LinalgError;
+
+/// Sweeps `svd` allows before giving up.
+const MAX_SWEEPS: usize = 100;
+
+/// Thin singular value decomposition `A = UΣVᵀ`.
+///
+/// For an `m × n` matrix with `k = min(m, n)`, `U` is `m × k` and `Vᵀ` is
+/// `k × n`, both with orthonormal rows or columns, and the `k` singular
+/// values are sorted in descending order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Svd<K> {
+    u: Matrix<K>,
+    singular_values: Vec<K>,
+    vt: Matrix<K>,
+}
+
+impl<K: Float> Matrix<K> {
+    /// One-sided Jacobi SVD: rotates pairs of columns until they are all
+    /// orthogonal to machine precision, the column norms then being the
+    /// singular values.
+    ///
+    /// Singular values below `ε·‖A‖_F` come out as exactly zero. Wide
+    /// matrices are decomposed through their transpose. Fails with
+    /// `NoConvergence` after 100 sweeps.
+    pub fn svd(&self) -> Result<Svd<K>, LinalgError> {
+        let (rows, cols) = self.shape();
+        if rows < cols {
+            let transposed = self.conjugate_transpose().svd()?;
+            return Ok(Svd {
+                u: transposed.vt.conjugate_transpose(),
+                singular_values: transposed.singular_values,
+                vt: transposed.u.conjugate_transpose(),
+            });
+        }
+
+        let mut u = self.clone();
+        let mut v: Matrix<K> = Matrix::identity(cols);
+        let mut frobenius = K::zero();
+        for &value in self.as_slice() {
+            frobenius = frobenius + value * value;
+        }
+        // columns this small are rounding noise of a zero singular value
+        let negligible = frobenius * K::epsilon() * K::epsilon();
+        let mut sweeps = 0;
+        while rotate_sweep(&mut u, &mut v, negligible) {
+            sweeps += 1;
+            if sweeps == MAX_SWEEPS {
+                return Err(LinalgError::NoConvergence { iterations: sweeps });
+            }
+        }
+
+        let norms: Vec<K> = (0..cols)
+            .map(|j| {
+                let mut norm = K::zero();
+                for i in 0..rows {
+                    norm = norm + u[(i, j)] * u[(i, j)];
+                }
+                if norm <= negligible {
+                    K::zero()
+                } else {
+                    norm.sqrt()
+                }
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..cols).collect();
+        order.sort_by(|&i, &j| {
+            norms[j]
+                .partial_cmp(&norms[i])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut sorted_u = Matrix::zeros(rows, cols);
+        let mut vt = Matrix::zeros(cols, cols);
+        for (column, &from) in order.iter().enumerate() {
+            let norm = norms[from];
+            if !norm.is_zero() {
+                for i in 0..rows {
+                    sorted_u[(i, column)] = u[(i, from)] / norm;
+                }
+            }
+            for i in 0..cols {
+                vt[(column, i)] = v[(i, from)];
+            }
+        }
+        let singular_values: Vec<K> = order.iter().map(|&i| norms[i]).collect();
+        complete_columns(&mut sorted_u, &singular_values);
+        Ok(Svd {
+            u: sorted_u,
+            singular_values,
+            vt,
+        })
+    }
+}
+
+impl<K: Float> Matrix<K> {
+    /// Moore–Penrose pseudo-inverse `A⁺`, an `n × m` matrix for an `m × n`
+    /// `A` of any rank.
+    ///
+    /// Singular values at or below `tolerance`, by default
+    /// `Svd::default_tolerance`, are treated as zero. `A⁺b` is the
+    /// least-norm least-squares solution of `Ax = b`.
+    pub fn pseudo_inverse(&self, tolerance: Option<K>) -> Result<Matrix<K>, LinalgError> {
+        Ok(self.svd()?.pseudo_inverse(tolerance))
+    }
+}
+
+/// One Jacobi sweep over every column pair of `u`, accumulating the
+/// rotations in `v` and leaving alone columns whose squared norm is at most
+/// `negligible`. Returns whether any pair still needed rotating.
+fn rotate_sweep<K: Float>(u: &mut Matrix<K>, v: &mut Matrix<K>, negligible: K) -> bool {
+    let (rows, cols) = u.shape();
+    let two = K::one() + K::one();
+    let mut rotated = false;
+    for p in 0..cols {
+        for q in p + 1..cols {
+            let (mut alpha, mut beta, mut gamma) = (K::zero(), K::zero(), K::zero());
+            for i in 0..rows {
+                alpha = alpha + u[(i, p)] * u[(i, p)];
+                beta = beta + u[(i, q)] * u[(i, q)];
+                gamma = gamma + u[(i, p)] * u[(i, q)];
+            }
+            if alpha <= negligible
+                || beta <= negligible
+                || gamma.abs() <= K::epsilon() * (alpha * beta).sqrt()
+            {
+                continue;
+            }
+            rotated = true;
+            let zeta = (beta - alpha) / (two * gamma);
+            let t = (zeta.abs() + (K::one() + zeta * zeta).sqrt()).recip();
+            let t = if zeta < K::zero() { -t } else { t };
+            let c = (K::one() + t * t).sqrt().recip();
+            let s = c * t;
+            for target in [&mut *u, &mut *v] {
+                for i in 0..target.shape().0 {
+                    let (ip, iq) = (target[(i, p)], target[(i, q)]);
+                    target[(i, p)] = c * ip - s * iq;
+                    target[(i, q)] = s * ip + c * iq;
+                }
+            }
+        }
+    }
+    rotated
+}
+
+/// Replaces the zero columns of `u`, left by zero singular values, with unit
+/// vectors orthogonal to every other column.
+///
+/// Each column comes from the standard basis vector with the largest
+/// residual once projected off the other columns, orthogonalized twice so
+/// that no rounding drift is left behind.
+fn complete_columns<K: Float>(u: &mut Matrix<K>, singular_values: &[K]) {
+    let (rows, cols) = u.shape();
+    for column in 0..cols {
+        if !singular_values[column].is_zero() {
+            continue;
+        }
+        let mut best = (K::zero(), Vec::new());
+        for candidate in 0..rows {
+            let mut x = vec![K::zero(); rows];
+            x[candidate] = K::one();
+            project_out(u, column, &mut x);
+            let norm = squared_norm(&x);
+            if norm > best.0 {
+                best = (norm, x);
+            }
+        }
+        let mut x = best.1;
+        project_out(u, column, &mut x);
+        let norm = squared_norm(&x).sqrt();
+        for (i, xi) in x.into_iter().enumerate() {
+            u[(i, column)] = xi / norm;
+        }
+    }
+}
+
+/// Subtracts from `x` its projection on every column of `u` but `column`.
+fn project_out<K: Float>(u: &Matrix<K>, column: usize, x: &mut [K]) {
+    for other in (0..u.shape().1).filter(|&j| j != column) {
+        let mut projection = K::zero();
+        for (i, &xi) in x.iter().enumerate() {
+            projection = projection + u[(i, other)] * xi;
+        }
+        for (i, xi) in x.iter_mut().enumerate() {
+            *xi = *xi - projection * u[(i, other)];
+        }
+    }
+}
+
+fn squared_norm<K: Float>(x: &[K]) -> K {
+    x.iter().fold(K::zero(), |acc, &xi| acc + xi * xi)
+}
+
+impl<K: Float> Svd<K> {
+    pub fn u(&self) -> &Matrix<K> {
+        &self.u
+    }
+
+    pub fn singular_values(&self) -> &[K] {
+        &self.singular_values
+    }
+
+    pub fn vt(&self) -> &Matrix<K> {
+        &self.vt
+    }
+
+    /// The default rank cutoff `max(m, n)·σ₁·ε`.
+    pub fn default_tolerance(&self) -> K {
+        let (rows, cols) = (self.u.shape().0, self.vt.shape().1);
+        K::from_usize(rows.max(cols)) * self.norm_2() * K::epsilon()
+    }
+
+    /// Number of singular values above `default_tolerance`.
+    pub fn rank(&self) -> usize {
+        self.rank_with(self.default_tolerance())
+    }
+
+    /// Number of singular values strictly above `tolerance`.
+    pub fn rank_with(&self, tolerance: K) -> usize {
+        self.singular_values
+            .iter()
+            .filter(|&&sigma| sigma > tolerance)
+            .count()
+    }
+
+    /// Largest singular value, `‖A‖₂`.
+    pub fn norm_2(&self) -> K {
+        self.singular_values.first().copied().unwrap_or(K::zero())
+    }
+
+    /// `σ_max / σ_min`, infinite for a singular matrix.
+    pub fn condition_number(&self) -> K {
+        match self.singular_values.last() {
+            Some(smallest) if !smallest.is_zero() => self.norm_2() / *smallest,
+            Some(_) => K::from_f64(f64::INFINITY),
+            None => K::zero(),
+        }
+    }
+
+    /// Best approximation of `A` of rank at most `rank` in the 2-norm,
+    /// keeping the `rank` largest singular values.
+    pub fn low_rank(&self, rank: usize) -> Matrix<K> {
+        let (rows, cols) = (self.u.shape().0, self.vt.shape().1);
+        let mut result = Matrix::zeros(rows, cols);
+        for (k, &sigma) in self.singular_values.iter().enumerate().take(rank) {
+            for i in 0..rows {
+                let factor = sigma * self.u[(i, k)];
+                for j in 0..cols {
+                    result[(i, j)] = result[(i, j)] + factor * self.vt[(k, j)];
+                }
+            }
+        }
+        result
+    }
+
+    /// `VΣ⁺Uᵀ`, inverting only the singular values above `tolerance`.
+    pub fn pseudo_inverse(&self, tolerance: Option<K>) -> Matrix<K> {
+        let tolerance = tolerance.unwrap_or_else(|| self.default_tolerance());
+        let (rows, cols) = (self.u.shape().0, self.vt.shape().1);
+        let mut result = Matrix::zeros(cols, rows);
+        for (k, &sigma) in self.singular_values.iter().enumerate() {
+            if sigma <= tolerance {
+                continue;
+            }
+            for i in 0..cols {
+                let factor = self.vt[(k, i)] / sigma;
+                for j in 0..rows {
+                    result[(i, j)] = result[(i, j)] + factor * self.u[(j, k)];
+                }
+            }
+        }
+        result
+    }
+
+    /// Recombines the factors into `UΣVᵀ`.
+    pub fn recompose(&self) -> Matrix<K> {
+        self.low_rank(self.singular_values.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector;
+    use crate::{assert_approx_eq, Approx};
+
+    fn check(a: &Matrix<f64>, svd: &Svd<f64>) {
+        let size = svd.singular_values().len();
+        assert_eq!(size, a.shape().0.min(a.shape().1));
+        let (u, vt) = (svd.u(), svd.vt());
+        assert_approx_eq!(
+            &u.conjugate_transpose() * u,
+            &Matrix::identity(size),
+            Approx::Absolute(1e-12)
+        );
+        assert_approx_eq!(
+            vt * &vt.conjugate_transpose(),
+            &Matrix::identity(size),
+            Approx::Absolute(1e-12)
+        );
+        assert_approx_eq!(&svd.recompose(), a, Approx::Absolute(1e-12));
+        assert!(svd.singular_values().windows(2).all(|w| w[0] >= w[1]));
+        assert!(svd.singular_values().iter().all(|&sigma| sigma >= 0.));
+    }
+
+    #[test]
+    fn svd_values() {
+        let a = Matrix::from(&[&[3., 0.], &[4., 5.]]);
+        let svd = a.svd().unwrap();
+        check(&a, &svd);
+        let expected = [45_f64.sqrt(), 5_f64.sqrt()];
+        for (value, expected) in svd.singular_values().iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        assert!((svd.norm_2() - 45_f64.sqrt()).abs() < 1e-12);
+        assert!((svd.condition_number() - 3.).abs() < 1e-12);
+        assert_eq!(svd.rank(), 2);
+
+        let tall = Matrix::from(&[&[1., 2.], &[3., 4.], &[5., 6.], &[7., 8.]]);
+        check(&tall, &tall.svd().unwrap());
+        let wide = tall.conjugate_transpose();
+        let svd = wide.svd().unwrap();
+        assert_eq!(svd.u().shape(), (2, 2));
+        assert_eq!(svd.vt().shape(), (2, 4));
+        check(&wide, &svd);
+    }
+
+    #[test]
+    fn svd_rank_deficient() {
+        let a = Matrix::from(&[&[1., 2., 3.], &[2., 4., 6.], &[1., 0., 1.]]);
+        let svd = a.svd().unwrap();
+        check(&a, &svd);
+        assert_eq!(svd.rank(), 2);
+        assert_eq!(svd.rank_with(1.), 1);
+        assert!(svd.condition_number() > 1e12);
+
+        let zero: Matrix<f64> = Matrix::zeros(3, 2);
+        let svd = zero.svd().unwrap();
+        check(&zero, &svd);
+        assert_eq!(svd.rank(), 0);
+        assert_eq!(svd.condition_number(), f64::INFINITY);
+
+        // the best rank 1 approximation keeps the dominant direction only
+        let a = Matrix::from(&[&[2., 0.], &[0., 1.]]);
+        let svd = a.svd().unwrap();
+        assert_approx_eq!(
+            &svd.low_rank(1),
+            &Matrix::from(&[&[2., 0.], &[0., 0.]]),
+            Approx::Absolute(1e-12)
+        );
+
+        // I − 11ᵀ/n has a one dimensional null space to complete in U
+        let size = 10;
+        let mut centering: Matrix<f64> = Matrix::identity(size);
+        for value in centering.as_mut_slice() {
+            *value -= 1. / size as f64;
+        }
+        let svd = centering.svd().unwrap();
+        check(&centering, &svd);
+        assert_eq!(svd.rank(), size - 1);
+    }
+
+    fn check_penrose(a: &Matrix<f64>, pinv: &Matrix<f64>) {
+        let (rows, cols) = a.shape();
+        assert_eq!(pinv.shape(), (cols, rows));
+        assert_approx_eq!(&(a * pinv) * a, a, Approx::Absolute(1e-12));
+        assert_approx_eq!(&(pinv * a) * pinv, pinv, Approx::Absolute(1e-12));
+        let left = a * pinv;
+        assert_approx_eq!(&left.conjugate_transpose(), &left, Approx::Absolute(1e-12));
+        let right = pinv * a;
+        assert_approx_eq!(
+            &right.conjugate_transpose(),
+            &right,
+            Approx::Absolute(1e-12)
+        );
+    }
+
+    #[test]
+    fn pseudo_inverse() {
+        let a = Matrix::from(&[&[4., 7.], &[2., 6.]]);
+        let pinv = a.pseudo_inverse(None).unwrap();
+        assert_approx_eq!(
+            &pinv,
+            &a.clone().inverse().unwrap(),
+            Approx::Absolute(1e-12)
+        );
+
+        let tall = Matrix::from(&[&[1., 2.], &[3., 4.], &[5., 6.]]);
+        check_penrose(&tall, &tall.pseudo_inverse(None).unwrap());
+        let wide = tall.conjugate_transpose();
+        let pinv = wide.pseudo_inverse(None).unwrap();
+        check_penrose(&wide, &pinv);
+
+        // least-norm solution of the underdetermined x + y = 2
+        let a: Matrix<f64> = Matrix::from(&[&[1., 1.]]);
+        let x = &a.pseudo_inverse(None).unwrap() * &Vector::from(&[2.]);
+        assert!((x.positions[0] - 1.).abs() < 1e-12 && (x.positions[1] - 1.).abs() < 1e-12);
+
+        let singular = Matrix::from(&[&[1., 2.], &[2., 4.]]);
+        let pinv = singular.pseudo_inverse(None).unwrap();
+        check_penrose(&singular, &pinv);
+        assert_approx_eq!(
+            &pinv,
+            &Matrix::from(&[&[0.04, 0.08], &[0.08, 0.16]]),
+            Approx::Absolute(1e-12)
+        );
+
+        let zero: Matrix<f64> = Matrix::zeros(2, 3);
+        assert_eq!(zero.pseudo_inverse(None).unwrap(), Matrix::zeros(3, 2));
+
+        // a loose tolerance drops the small singular value
+        let a = Matrix::from(&[&[1., 0.], &[0., 1e-9]]);
+        let pinv = a.pseudo_inverse(Some(1e-6)).unwrap();
+        assert_approx_eq!(
+            &pinv,
+            &Matrix::from(&[&[1., 0.], &[0., 0.]]),
+            Approx::Absolute(1e-12)
+        );
+    }
+
+    #[test]
+    fn svd_f32() {
+        let a: Matrix<f32> = Matrix::from(&[&[1., 1.], &[0., 1.], &[1., 0.]]);
+        let svd = a.svd().unwrap();
+        for (value, expected) in svd.singular_values().iter().zip([3_f32.sqrt(), 1.]) {
+            assert!((value - expected).abs() < 1e-5);
+        }
+        assert_eq!(svd.rank(), 2);
+    }
+}
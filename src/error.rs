@@ -0,0 +1,73 @@
+use std::fmt;
+
+/// Why a vector or matrix operation could not be carried out.
+///
+/// Shapes are reported as `(rows, columns)`; a vector of size `n` is
+/// reported as `(n, 1)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LinalgError {
+    /// The operands' shapes are incompatible for the operation.
+    ShapeMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// Rows passed to a matrix constructor have different lengths.
+    RaggedRows {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The operation needs a square matrix.
+    NotSquare { shape: (usize, usize) },
+    /// The matrix has no inverse.
+    Singular,
+    /// The matrix is not (Hermitian) positive definite.
+    NotPositiveDefinite,
+    /// The operation needs a symmetric (Hermitian) matrix.
+    NotSymmetric,
+    /// An iterative algorithm hit its iteration limit before converging.
+    NoConvergence { iterations: usize },
+    /// The operation needs at least one element.
+    Empty,
+    /// The operation is only defined for some sizes, e.g. the cross product.
+    UnsupportedDimension(usize),
+    /// A scalar argument is outside the range the operation accepts, e.g. a
+    /// negative ridge penalty.
+    InvalidParameter { name: &'static str },
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::ShapeMismatch { left, right } => write!(
+                f,
+                "shape mismatch: {}x{} and {}x{}",
+                left.0, left.1, right.0, right.1
+            ),
+            LinalgError::RaggedRows {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "ragged rows: row {row} has {found} columns, expected {expected}"
+            ),
+            LinalgError::NotSquare { shape } => {
+                write!(f, "matrix is not square: {}x{}", shape.0, shape.1)
+            }
+            LinalgError::Singular => write!(f, "matrix is singular"),
+            LinalgError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            LinalgError::NotSymmetric => write!(f, "matrix is not symmetric"),
+            LinalgError::NoConvergence { iterations } => {
+                write!(f, "no convergence after {iterations} iterations")
+            }
+            LinalgError::Empty => write!(f, "empty vector or matrix"),
+            LinalgError::UnsupportedDimension(dimension) => {
+                write!(f, "unsupported dimension: {dimension}")
+            }
+            LinalgError::InvalidParameter { name } => write!(f, "invalid parameter: {name}"),
+        }
+    }
+}
+
+impl std::error::Error for LinalgError {}
@@ -0,0 +1,238 @@
+use crate::types::{Float, Matrix, Vector};
+use crate::LinalgError;
+
+/// A fitted linear model `y ≈ Xβ`.
+#[derive(Clone)]
+pub struct Regression<K> {
+    pub coefficients: Vector<K>,
+    /// `y - Xβ`, one entry per observation.
+    pub residuals: Vector<K>,
+    /// Coefficient of determination `1 - SS_res / SS_tot`.
+    pub r_squared: K,
+    /// `‖y - Xβ‖₂`.
+    pub residual_norm: K,
+}
+
+impl<K: Float> Matrix<K> {
+    /// Minimizes `‖Ax - b‖₂` through the thin QR factorization of `A`.
+    ///
+    /// Fails with `ShapeMismatch` when `b` has the wrong length and with
+    /// `Singular` when `A` does not have full column rank, in which case
+    /// `pseudo_inverse` gives the least-norm solution instead.
+    pub fn least_squares(&self, b: &Vector<K>) -> Result<Vector<K>, LinalgError> {
+        if b.size() != self.shape().0 {
+            return Err(LinalgError::ShapeMismatch {
+                left: self.shape(),
+                right: (b.size(), 1),
+            });
+        }
+        let (rows, cols) = self.shape();
+        if rows < cols {
+            return Err(LinalgError::Singular);
+        }
+        let (q, r) = self.thin_qr().into_parts();
+        let mut largest = K::zero();
+        for i in 0..cols {
+            if r[(i, i)].abs() > largest {
+                largest = r[(i, i)].abs();
+            }
+        }
+        let tolerance = K::from_usize(rows) * largest * K::epsilon();
+
+        let mut x = q.conjugate_transpose().try_mul_vec(b)?.positions;
+        for row in (0..cols).rev() {
+            if r[(row, row)].abs() <= tolerance {
+                return Err(LinalgError::Singular);
+            }
+            for column in row + 1..cols {
+                x[row] = x[row] - r[(row, column)] * x[column];
+            }
+            x[row] = x[row] / r[(row, row)];
+        }
+        Ok(Vector { positions: x })
+    }
+
+    /// Minimizes `‖Ax - b‖₂² + λ‖x‖₂²` by solving the least squares problem
+    /// for `A` stacked on `√λ·I`, which has full column rank for any `λ > 0`.
+    ///
+    /// Fails with `InvalidParameter` unless `λ` is finite and non-negative.
+    pub fn ridge_least_squares(&self, b: &Vector<K>, lambda: K) -> Result<Vector<K>, LinalgError> {
+        // also rejects NaN
+        if !(lambda >= K::zero() && lambda < K::from_f64(f64::INFINITY)) {
+            return Err(LinalgError::InvalidParameter { name: "lambda" });
+        }
+        if b.size() != self.shape().0 {
+            return Err(LinalgError::ShapeMismatch {
+                left: self.shape(),
+                right: (b.size(), 1),
+            });
+        }
+        let (rows, cols) = self.shape();
+        let mut stacked = Matrix::zeros(rows + cols, cols);
+        for row in 0..rows {
+            stacked.row_mut(row).copy_from_slice(self.row(row));
+        }
+        let root = lambda.sqrt();
+        for i in 0..cols {
+            stacked[(rows + i, i)] = root;
+        }
+        let mut target = b.positions.clone();
+        target.resize(rows + cols, K::zero());
+        stacked.least_squares(&Vector { positions: target })
+    }
+}
+
+impl<K: Float> Regression<K> {
+    /// Ordinary least squares fit of `targets` on the columns of `design`.
+    ///
+    /// Add a column of ones to `design` to fit an intercept.
+    pub fn fit(design: &Matrix<K>, targets: &Vector<K>) -> Result<Self, LinalgError> {
+        let coefficients = design.least_squares(targets)?;
+        Self::evaluate(design, targets, coefficients)
+    }
+
+    /// Ridge regression with penalty `lambda` on every coefficient.
+    pub fn fit_ridge(
+        design: &Matrix<K>,
+        targets: &Vector<K>,
+        lambda: K,
+    ) -> Result<Self, LinalgError> {
+        let coefficients = design.ridge_least_squares(targets, lambda)?;
+        Self::evaluate(design, targets, coefficients)
+    }
+
+    /// When the targets are all equal `SS_tot` is zero and `r_squared` is
+    /// one for an exact fit, zero otherwise.
+    fn evaluate(
+        design: &Matrix<K>,
+        targets: &Vector<K>,
+        coefficients: Vector<K>,
+    ) -> Result<Self, LinalgError> {
+        let mut residuals = targets.clone();
+        residuals.try_sub(&design.try_mul_vec(&coefficients)?)?;
+
+        let mut count = K::zero();
+        let mut mean = K::zero();
+        for &y in targets.positions.iter() {
+            count = count + K::one();
+            mean = mean + y;
+        }
+        if !count.is_zero() {
+            mean = mean / count;
+        }
+        let mut total = K::zero();
+        for &y in targets.positions.iter() {
+            total = total + (y - mean) * (y - mean);
+        }
+        let mut residual = K::zero();
+        for &e in residuals.positions.iter() {
+            residual = residual + e * e;
+        }
+
+        let r_squared = if !total.is_zero() {
+            K::one() - residual / total
+        } else if residual.is_zero() {
+            K::one()
+        } else {
+            K::zero()
+        };
+        Ok(Regression {
+            coefficients,
+            residuals,
+            r_squared,
+            residual_norm: residual.sqrt(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_approx_eq, Approx};
+
+    #[test]
+    fn least_squares_fit() {
+        // exact system
+        let a = Matrix::from(&[&[2., 1.], &[1., 3.]]);
+        let x = a.least_squares(&Vector::from(&[3., 5.])).unwrap();
+        assert_approx_eq!(x, Vector::from(&[0.8, 1.4]), Approx::Absolute(1e-10));
+
+        // line through (0, 1), (1, 3), (2, 4), (3, 4)
+        let design: Matrix<f64> = Matrix::from(&[&[1., 0.], &[1., 1.], &[1., 2.], &[1., 3.]]);
+        let targets = Vector::from(&[1., 3., 4., 4.]);
+        let fit = Regression::fit(&design, &targets).unwrap();
+        assert_approx_eq!(
+            fit.coefficients,
+            Vector::from(&[1.5, 1.]),
+            Approx::Absolute(1e-10)
+        );
+        assert_approx_eq!(
+            fit.residuals,
+            Vector::from(&[-0.5, 0.5, 0.5, -0.5]),
+            Approx::Absolute(1e-10)
+        );
+        assert!((fit.residual_norm - 1.).abs() < 1e-12);
+        assert!((fit.r_squared - 5. / 6.).abs() < 1e-12);
+
+        let exact = Regression::fit(&design, &Vector::from(&[1., 3., 5., 7.])).unwrap();
+        assert!((exact.r_squared - 1.).abs() < 1e-12);
+        assert!(exact.residual_norm < 1e-12);
+    }
+
+    #[test]
+    fn least_squares_ridge() {
+        let a = Matrix::from(&[&[1., 0.], &[0., 1.], &[0., 0.]]);
+        let b = Vector::from(&[2., 4., 1.]);
+        // (AᵀA + λI)x = Aᵀb with AᵀA = I
+        let x = a.ridge_least_squares(&b, 1.).unwrap();
+        assert_approx_eq!(x, Vector::from(&[1., 2.]), Approx::Absolute(1e-10));
+        assert_approx_eq!(
+            a.ridge_least_squares(&b, 0.).unwrap(),
+            Vector::from(&[2., 4.]),
+            Approx::Absolute(1e-10)
+        );
+
+        // ridge regularizes a rank deficient design
+        let singular: Matrix<f64> = Matrix::from(&[&[1., 1.], &[2., 2.]]);
+        let targets = Vector::from(&[1., 2.]);
+        assert!(singular.least_squares(&targets).is_err());
+        let fit = Regression::fit_ridge(&singular, &targets, 1e-3).unwrap();
+        assert!((fit.coefficients.positions[0] - fit.coefficients.positions[1]).abs() < 1e-12);
+        assert!(fit.r_squared > 0.99);
+
+        for lambda in [-1., f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                a.ridge_least_squares(&b, lambda).map(|x| x.positions),
+                Err(LinalgError::InvalidParameter { name: "lambda" })
+            );
+        }
+        assert_eq!(
+            LinalgError::InvalidParameter { name: "lambda" }.to_string(),
+            "invalid parameter: lambda"
+        );
+    }
+
+    #[test]
+    fn least_squares_errors() {
+        let a = Matrix::from(&[&[1., 2.], &[2., 4.], &[3., 6.]]);
+        assert_eq!(
+            a.least_squares(&Vector::from(&[1., 2., 3.]))
+                .map(|x| x.positions),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            a.least_squares(&Vector::from(&[1., 2.]))
+                .map(|x| x.positions),
+            Err(LinalgError::ShapeMismatch {
+                left: (3, 2),
+                right: (2, 1)
+            })
+        );
+        let wide = Matrix::from(&[&[1., 2.]]);
+        assert_eq!(
+            wide.least_squares(&Vector::from(&[1.]))
+                .map(|x| x.positions),
+            Err(LinalgError::Singular)
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Linear algebra over generic scalars: the `Vector` and `Matrix` types and
+//! the free functions of the `mandatory` exercises.
+//!
+//! Most users only need the prelude:
+//!
+//! ```
+//! use matrix::prelude::*;
+//!
+//! let u = Vector::from(&[1., 0., 0.]);
+//! let v = Vector::from(&[0., 1., 0.]);
+//! assert_eq!(cross_product(&u, &v).positions, vec![0., 0., 1.]);
+//! ```
+
+pub mod approx;
+pub mod condition;
+pub mod decomposition;
+mod error;
+pub mod least_squares;
+pub mod mandatory;
+pub mod prelude;
+pub mod solve;
+pub mod subspace;
+pub mod types;
+
+pub use prelude::*;
@@ -1,11 +1,9 @@
+use matrix::prelude::*;
 use std::io;
-mod mandatory;
-mod types;
-use crate::mandatory::cosine::angle_cos;
-use crate::mandatory::cross_product::cross_product;
-use crate::mandatory::linear_combination::linear_combination;
-use crate::mandatory::linear_interpolation::lerp;
-use crate::types::{Matrix, Vector};
+
+/// Share of the `f64::DIGITS` significant digits that an inverse may lose,
+/// `log10(κ)` of them, before the demo warns about ill-conditioning.
+const LOST_DIGITS_WARNING: f64 = 0.25;
 
 fn main() {
     println!("Welcome to the matrix ! Choose the exercice you want to check 💊:");
@@ -148,13 +146,13 @@ fn main() {
         10 => {
             println!("\nExercise 10 - Row-echelon form");
             println!("-------------------------------------");
-            let mut u = Matrix::from(&[&[1, 0, 0], &[0, 1, 0], &[0, 0, 1]]);
+            let mut u = Matrix::from(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., 1.]]);
             println!("{}", u.row_echelon());
             println!("-------------------------------------");
-            let mut u = Matrix::from(&[&[1, 2], &[3, 4]]);
+            let mut u = Matrix::from(&[&[1., 2.], &[3., 4.]]);
             println!("{}", u.row_echelon());
             println!("-------------------------------------");
-            let mut u = Matrix::from(&[&[1, 2], &[2, 4]]);
+            let mut u = Matrix::from(&[&[1., 2.], &[2., 4.]]);
             println!("{}", u.row_echelon());
             println!("-------------------------------------");
             let mut u = Matrix::from(&[
@@ -164,6 +162,11 @@ fn main() {
             ]);
             println!("{}", u.row_echelon());
             println!("-------------------------------------");
+            let u = Matrix::from(&[&[2., 1., -1.], &[-3., -1., 2.], &[-2., 1., 2.]]);
+            println!("Step by step reduction of {u}\n");
+            let (_, log) = u.rref_with_log();
+            println!("{log}");
+            println!("-------------------------------------");
         }
         11 => {
             println!("\nExercise 11 - Determinant\n");
@@ -207,6 +210,55 @@ fn main() {
                     println!("{m}");
                 }
             }
+            println!("-------------------------------------");
+            let u: Matrix<f64> = Matrix::from(&[&[1., 1.], &[1., 1.0001]]);
+            println!("inverse of : {u}");
+            println!("result:");
+            match u.inverse_with_condition(Norm::One) {
+                Ok((m, condition)) => {
+                    println!("{m}");
+                    println!("condition number (1-norm): {condition:e}");
+                    let digits_lost = condition.log10();
+                    if digits_lost > LOST_DIGITS_WARNING * f64::DIGITS as f64 {
+                        println!(
+                            "warning: ill-conditioned, about {digits_lost:.0} of {} digits may be lost",
+                            f64::DIGITS
+                        );
+                    }
+                }
+                Err(m) => {
+                    println!("{m}");
+                }
+            }
+            println!("-------------------------------------");
+            let r = Rational::from;
+            let mut u = Matrix::from(&[
+                &[r(8), r(5), r(-2)],
+                &[r(4), r(7), r(20)],
+                &[r(7), r(6), r(1)],
+            ]);
+            println!("inverse of : {u}");
+            println!("expected (exact):");
+            println!("[\n[113/174,17/174,-19/29]\n[-68/87,-11/87,28/29]\n[25/174,13/174,-6/29]\n]");
+            println!("result:");
+            match u.inverse() {
+                Ok(m) => {
+                    println!("{m}");
+                }
+                Err(m) => {
+                    println!("{m}");
+                }
+            }
+            println!("-------------------------------------");
+            println!("step by step:\n");
+            match u.inverse_with_log() {
+                Ok((_, log)) => {
+                    println!("{log}");
+                }
+                Err(m) => {
+                    println!("{m}");
+                }
+            }
         }
         13 => {
             println!("\nExercise 13 - Rank\n");
@@ -226,7 +278,7 @@ fn main() {
             println!("rank of matrix: {u}");
             println!("rank: {}", u.rank());
             println!("-------------------------------------");
-            let mut u = Matrix::from(&[&[1, 2], &[3, 4]]);
+            let mut u = Matrix::from(&[&[1., 2.], &[3., 4.]]);
 
             println!("rank of matrix: {u}");
             println!("rank: {}", u.rank());
@@ -354,13 +406,7 @@ fn ex03() {
     println!("got: {}", u.dot(v));
 }
 
-fn ex04<K>(u: Vector<K>, expected: [&str; 3])
-where
-    K: Clone,
-    K: Into<f32>,
-    K: std::ops::Mul<K, Output = K>,
-    K: std::fmt::Display,
-{
+fn ex04<K: Scalar>(u: Vector<K>, expected: [&str; 3]) {
     println!("taxicab norm of vector: {u}");
     println!("expected: {}", expected[0]);
     println!("got {}", u.norm_1());
@@ -375,104 +421,48 @@ where
     println!("-------------------------------------");
 }
 
-fn ex05<K>(u: &Vector<K>, v: &Vector<K>, expected: f32)
-where
-    K: Copy,
-    K: Into<f32>,
-    K: std::ops::Add<K, Output = K>,
-    K: std::ops::Sub<K, Output = K>,
-    K: std::ops::Mul<K, Output = K>,
-    K: std::fmt::Display,
-{
+fn ex05<K: Scalar>(u: &Vector<K>, v: &Vector<K>, expected: f32) {
     println!("cosines of {v} and {u}");
     println!("expected: {expected}");
     println!("got: {}", angle_cos::<K>(u, v));
 }
 
-fn ex06<K>(u: &Vector<K>, v: &Vector<K>, expected: &str)
-where
-    K: Copy,
-    K: std::ops::Add<K, Output = K>,
-    K: std::ops::Sub<K, Output = K>,
-    K: std::ops::Mul<K, Output = K>,
-    K: std::fmt::Display,
-{
+fn ex06<K: Scalar>(u: &Vector<K>, v: &Vector<K>, expected: &str) {
     println!("Cross product of {u} and {v}");
     println!("expected: {expected}");
     println!("got: {}", cross_product(u, v));
     println!("-------------------------------------");
 }
 
-fn ex07_with_vec<K>(mat: &mut Matrix<K>, vec: Vector<K>, expected: &str)
-where
-    K: Copy,
-    K: std::ops::Add<K, Output = K>,
-    K: std::ops::Sub<K, Output = K>,
-    K: std::ops::Mul<K, Output = K>,
-    K: std::fmt::Display,
-    K: PartialOrd,
-{
+fn ex07_with_vec<K: Scalar>(mat: &mut Matrix<K>, vec: Vector<K>, expected: &str) {
     println!("mul_vec {mat} with {vec}");
     println!("expected: {expected}");
     println!("got: {}", mat.mul_vec(vec));
     println!("-------------------------------------");
 }
 
-fn ex07_with_mat<K>(u: &mut Matrix<K>, mat: Matrix<K>, expected: &str)
-where
-    K: Copy,
-    K: std::ops::Add<K, Output = K>,
-    K: std::ops::Sub<K, Output = K>,
-    K: std::ops::Mul<K, Output = K>,
-    K: std::fmt::Display,
-    K: PartialOrd,
-{
+fn ex07_with_mat<K: Scalar>(u: &mut Matrix<K>, mat: Matrix<K>, expected: &str) {
     println!("mul_mat {u} with {mat}");
     println!("expected: \n{expected}");
     println!("got: {}", u.mul_mat(mat));
     println!("-------------------------------------");
 }
 
-fn ex08<K>(u: &mut Matrix<K>, expected: &str)
-where
-    K: Copy,
-    K: std::ops::Add<K, Output = K>,
-    K: std::ops::Sub<K, Output = K>,
-    K: std::ops::Mul<K, Output = K>,
-    K: std::fmt::Display,
-    K: PartialOrd,
-{
+fn ex08<K: Scalar>(u: &mut Matrix<K>, expected: &str) {
     println!("Trace of {u}");
     println!("expected: {expected}");
     println!("got: {}", u.trace());
     println!("-------------------------------------");
 }
 
-fn ex09<K>(u: &mut Matrix<K>, expected: &str)
-where
-    K: Copy,
-    K: std::ops::Add<K, Output = K>,
-    K: std::ops::Sub<K, Output = K>,
-    K: std::ops::Mul<K, Output = K>,
-    K: std::fmt::Display,
-    K: PartialOrd,
-{
+fn ex09<K: Scalar>(u: &mut Matrix<K>, expected: &str) {
     println!("Transpose of \n {}", u);
     println!("expected: \n{expected}");
     println!("got: {}", u.transpose());
     println!("-------------------------------------");
 }
 
-fn ex11<K>(u: &mut Matrix<K>, expected: &str)
-where
-    K: Copy,
-    K: std::default::Default,
-    K: std::ops::Add<K, Output = K>,
-    K: std::ops::Sub<K, Output = K>,
-    K: std::ops::Mul<K, Output = K>,
-    K: std::fmt::Display,
-    K: PartialOrd,
-{
+fn ex11<K: Scalar>(u: &mut Matrix<K>, expected: &str) {
     println!("Determinant of {u}");
     println!("expected: {expected}");
     println!("got: {}", u.determinant());
@@ -1,4 +1,5 @@
 pub mod cosine;
 pub mod cross_product;
+pub mod gram_schmidt;
 pub mod linear_combination;
 pub mod linear_interpolation;
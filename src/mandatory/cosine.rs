@@ -1,78 +1,83 @@
-use crate::types::Vector;
-use std::ops::Add;
-use std::ops::Mul;
-use std::ops::Sub;
-
-pub fn angle_cos<K>(u: &Vector<K>, v: &Vector<K>) -> f32
-where
-    K: Add<Output = K> + Mul<Output = K> + Sub<Output = K> + Copy,
-    K: Into<f32>,
-{
-    if u.size() != v.size() {
-        panic!("Vectors must have the same dimension to be able to compute there cosines");
-    }
+use crate::types::{Real, Scalar, Vector};
+use crate::LinalgError;
 
-    if u.positions.is_empty() || v.positions.is_empty() {
-        panic!("One the Vectors are 0");
-    }
-    let dot_product: K = u.dot(v.clone());
+/// Cosine of the angle between `u` and `v`. For complex vectors this is the
+/// real angle, taken from the real part of the Hermitian product.
+pub fn angle_cos<K: Scalar>(u: &Vector<K>, v: &Vector<K>) -> f32 {
+    try_angle_cos(u, v).unwrap_or_else(|e| panic!("Can't compute cosine: {e}"))
+}
+
+pub fn try_angle_cos<K: Scalar>(u: &Vector<K>, v: &Vector<K>) -> Result<f32, LinalgError> {
+    let dot_product: K = u.try_dot(v)?;
     let u_norm = u.norm();
     let v_norm = v.norm();
 
-    dot_product.into() / (u_norm * v_norm)
+    Ok(dot_product.re().to_f32() / (u_norm * v_norm))
 }
 
 #[cfg(test)]
-
 mod tests {
     use super::*;
+    use crate::Complex;
 
     #[test]
     fn angle_cos_with_0() {
         let u = Vector::from(&[1., 0.]);
         let v = Vector::from(&[1., 0.]);
-        assert_eq!(angle_cos(&u, &v), 1.0);
+        crate::assert_approx_eq!(angle_cos(&u, &v), 1.0);
 
         let u = Vector::from(&[1., 0.]);
         let v = Vector::from(&[0., 1.]);
-        assert_eq!(angle_cos(&u, &v), 0.0);
+        crate::assert_approx_eq!(angle_cos(&u, &v), 0.0);
     }
 
     #[test]
     fn angle_cos_with_negative() {
         let u = Vector::from(&[-1., 1.]);
         let v = Vector::from(&[1., -1.]);
-        assert_eq!(angle_cos(&u, &v), -1.0000001)
+        crate::assert_approx_eq!(angle_cos(&u, &v), -1.0)
     }
 
     #[test]
     fn angle_cos_basics() {
         let u = Vector::from(&[2., 1.]);
         let v = Vector::from(&[4., 2.]);
-        assert_eq!(angle_cos(&u, &v), 1.0);
+        crate::assert_approx_eq!(angle_cos(&u, &v), 1.0);
 
         let u = Vector::from(&[1., 2., 3.]);
         let v = Vector::from(&[4., 5., 6.]);
-        assert_eq!(angle_cos(&u, &v), 0.9746319);
+        crate::assert_approx_eq!(angle_cos(&u, &v), 0.9746319);
     }
 
     #[test]
     fn angle_cos_complete() {
         let u = Vector::from(&[8., 7.]);
         let v = Vector::from(&[3., 2.]);
-        assert_eq!(angle_cos(&u, &v), 0.99145424);
+        crate::assert_approx_eq!(angle_cos(&u, &v), 0.99145424);
 
         let u = Vector::from(&[1., 1.]);
         let v = Vector::from(&[1., 1.]);
-        assert_eq!(angle_cos(&u, &v), 1.0000001);
+        crate::assert_approx_eq!(angle_cos(&u, &v), 1.0);
 
         let u = Vector::from(&[4., 2.]);
         let v = Vector::from(&[1., 1.]);
-        assert_eq!(angle_cos(&u, &v), 0.94868326);
+        crate::assert_approx_eq!(angle_cos(&u, &v), 0.94868326);
 
         let u = Vector::from(&[-7., 3.]);
         let v = Vector::from(&[6., 4.]);
-        assert_eq!(angle_cos(&u, &v), -0.54626775);
+        crate::assert_approx_eq!(angle_cos(&u, &v), -0.54626775);
+    }
+
+    #[test]
+    fn angle_cos_complex() {
+        let u = Vector::from(&[Complex::new(1., 0.), Complex::new(0., 1.)]);
+        crate::assert_approx_eq!(angle_cos(&u, &u), 1.0);
+
+        let v = Vector::from(&[Complex::new(-1., 0.), Complex::new(0., -1.)]);
+        crate::assert_approx_eq!(angle_cos(&u, &v), -1.0);
+
+        let v = Vector::from(&[Complex::new(0., 1.), Complex::new(1., 0.)]);
+        crate::assert_approx_eq!(angle_cos(&u, &v), 0.0);
     }
 
     #[test]
@@ -81,15 +86,15 @@ mod tests {
         let v = Vector::from(&[3., 2.]);
         println!("test with\n{}\n{}", u, v);
         println!("{}", angle_cos(&u, &v));
-        assert_eq!(angle_cos(&u, &v), 0.99145424);
+        crate::assert_approx_eq!(angle_cos(&u, &v), 0.99145424);
         let norm_u_f32 = u.norm();
-        let norm_calculator_u_f32 = 10.630145812735 as f32;
+        let norm_calculator_u_f32 = 10.630_146_f32;
         assert_eq!(norm_u_f32, norm_calculator_u_f32);
         let norm_v_f32 = v.norm();
-        let norm_calculator_v_f32 = 3.605551275464 as f32;
+        let norm_calculator_v_f32 = 3.605_551_2_f32;
         assert_eq!(norm_v_f32, norm_calculator_v_f32);
         let dot_f32 = u.dot(v);
-        let dot_calculator = 38 as f32;
+        let dot_calculator = 38_f32;
         assert_eq!(dot_f32, dot_calculator);
         println!(
         "norm u : {} | norm from calculator online {}\nnorm v {} | norm v from calculator online {}\ndot {} | dot on calculator {}",
@@ -112,4 +117,19 @@ mod tests {
         );
         println!("------------------------------------------------------");
     }
+
+    #[test]
+    fn angle_cos_errors() {
+        let u = Vector::from(&[1., 0.]);
+        let v = Vector::from(&[1., 0., 0.]);
+        assert_eq!(
+            try_angle_cos(&u, &v),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 1),
+                right: (3, 1)
+            })
+        );
+        let empty: Vector<f32> = Vector::from(&[]);
+        assert_eq!(try_angle_cos(&empty, &empty), Err(LinalgError::Empty));
+    }
 }
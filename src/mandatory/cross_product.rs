@@ -1,21 +1,31 @@
-use crate::types::Vector;
-
-pub fn cross_product<K>(u: &Vector<K>, v: &Vector<K>) -> Vector<K>
-where
-    K: Copy + std::ops::Mul<Output = K> + std::ops::Sub<Output = K> + std::ops::Add<Output = K>,
-{
-    if u.size() != 3 || v.size() != 3 {
-        panic!("Cross product can only be computed on vector of size 3");
+use crate::types::{Scalar, Vector};
+use crate::LinalgError;
+
+pub fn cross_product<K: Scalar>(u: &Vector<K>, v: &Vector<K>) -> Vector<K> {
+    try_cross_product(u, v).unwrap_or_else(|e| panic!("Can't compute cross product: {e}"))
+}
+
+pub fn try_cross_product<K: Scalar>(
+    u: &Vector<K>,
+    v: &Vector<K>,
+) -> Result<Vector<K>, LinalgError> {
+    if u.size() != v.size() {
+        return Err(LinalgError::ShapeMismatch {
+            left: (u.size(), 1),
+            right: (v.size(), 1),
+        });
+    }
+    if u.size() != 3 {
+        return Err(LinalgError::UnsupportedDimension(u.size()));
     }
     let x = u.positions[1] * v.positions[2] - u.positions[2] * v.positions[1];
     let y = u.positions[2] * v.positions[0] - u.positions[0] * v.positions[2];
     let z = u.positions[0] * v.positions[1] - u.positions[1] * v.positions[0];
 
-    Vector::from(&[x, y, z])
+    Ok(Vector::from(&[x, y, z]))
 }
 
 #[cfg(test)]
-
 mod tests {
     use super::*;
 
@@ -84,4 +94,22 @@ mod tests {
 
         assert_eq!(res.positions, Vec::from([17., -58., -16.]));
     }
+
+    #[test]
+    fn cross_product_errors() {
+        let u = Vector::from(&[1., 0.]);
+        let v = Vector::from(&[0., 1.]);
+        assert_eq!(
+            try_cross_product(&u, &v).map(|w| w.positions),
+            Err(LinalgError::UnsupportedDimension(2))
+        );
+        let v = Vector::from(&[0., 1., 0.]);
+        assert_eq!(
+            try_cross_product(&u, &v).map(|w| w.positions),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 1),
+                right: (3, 1)
+            })
+        );
+    }
 }
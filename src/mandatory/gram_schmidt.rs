@@ -0,0 +1,151 @@
+use crate::types::{Float, Scalar, Vector};
+use crate::LinalgError;
+
+/// Output of `gram_schmidt`: the orthonormal basis, in input order, and the
+/// indices of the inputs that were dropped as linearly dependent.
+#[derive(Clone)]
+pub struct GramSchmidt<K> {
+    pub basis: Vec<Vector<K>>,
+    pub dependent: Vec<usize>,
+}
+
+/// Orthonormalizes `vectors` over real or complex floating point entries,
+/// which the normalization needs a true square root and division for.
+/// Integer vectors are rejected, their basis would truncate to zero:
+///
+/// ```compile_fail
+/// use matrix::{gram_schmidt, Vector};
+///
+/// gram_schmidt(&[Vector::from(&[3, 4]), Vector::from(&[1, 0])], 0);
+/// ```
+pub fn gram_schmidt<K: Scalar>(vectors: &[Vector<K>], tolerance: K::Real) -> GramSchmidt<K>
+where
+    K::Real: Float,
+{
+    try_gram_schmidt(vectors, tolerance).unwrap_or_else(|e| panic!("Can't orthonormalize: {e}"))
+}
+
+/// Modified Gram–Schmidt. An input is dependent when what is left of it
+/// after removing its projections on the basis so far has a norm of at most
+/// `tolerance` times its original norm; zero inputs are always dependent.
+pub fn try_gram_schmidt<K: Scalar>(
+    vectors: &[Vector<K>],
+    tolerance: K::Real,
+) -> Result<GramSchmidt<K>, LinalgError>
+where
+    K::Real: Float,
+{
+    if let Some(first) = vectors.first() {
+        for elt in vectors {
+            if elt.size() != first.size() {
+                return Err(LinalgError::ShapeMismatch {
+                    left: (first.size(), 1),
+                    right: (elt.size(), 1),
+                });
+            }
+        }
+    }
+
+    let mut basis: Vec<Vector<K>> = Vec::new();
+    let mut dependent: Vec<usize> = Vec::new();
+
+    for (index, vector) in vectors.iter().enumerate() {
+        let mut residual = vector.clone();
+        for q in basis.iter() {
+            let mut projection = q.clone();
+            projection.scl(q.try_dot(&residual)?);
+            residual.sub(&projection);
+        }
+
+        let norm = euclidean_norm(&residual);
+        if norm.is_zero() || norm <= tolerance * euclidean_norm(vector) {
+            dependent.push(index);
+            continue;
+        }
+        residual.scl(K::from_real(norm.recip()));
+        basis.push(residual);
+    }
+    Ok(GramSchmidt { basis, dependent })
+}
+
+/// `‖v‖` in the scalar's own precision, unlike the `f32` `Vector::norm`.
+fn euclidean_norm<K: Scalar>(v: &Vector<K>) -> K::Real {
+    let mut result = K::Real::zero();
+    for &point in v.positions.iter() {
+        result = result + (point.conj() * point).re();
+    }
+    result.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Real;
+    use crate::Complex;
+
+    fn assert_orthonormal<K: Scalar>(basis: &[Vector<K>]) {
+        for (i, u) in basis.iter().enumerate() {
+            for (j, v) in basis.iter().enumerate() {
+                let expected = if i == j { 1. } else { 0. };
+                let dot = u.try_dot(v).unwrap();
+                assert!((dot.abs().to_f32() - expected).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn gram_schmidt_basis() {
+        let vectors = [
+            Vector::from(&[1., 1., 0.]),
+            Vector::from(&[2., 2., 0.]),
+            Vector::from(&[1., 0., 1.]),
+            Vector::from(&[0., 0., 0.]),
+            Vector::from(&[3., 1., 2.]),
+        ];
+        let result = gram_schmidt(&vectors, 1e-10);
+        assert_eq!(result.basis.len(), 2);
+        assert_eq!(result.dependent, vec![1, 3, 4]);
+        assert_orthonormal(&result.basis);
+
+        let s = 0.5_f64.sqrt();
+        for (value, expected) in result.basis[0].positions.iter().zip([s, s, 0.]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn gram_schmidt_tolerance() {
+        let vectors = [Vector::from(&[1., 0.]), Vector::from(&[1., 1e-8])];
+        assert_eq!(gram_schmidt(&vectors, 1e-6).dependent, vec![1]);
+        let result = gram_schmidt(&vectors, 1e-12);
+        assert!(result.dependent.is_empty());
+        assert_orthonormal(&result.basis);
+    }
+
+    #[test]
+    fn gram_schmidt_complex() {
+        let vectors = [
+            Vector::from(&[Complex::new(1., 0.), Complex::new(0., 1.)]),
+            Vector::from(&[Complex::new(0., 1.), Complex::new(-1., 0.)]),
+            Vector::from(&[Complex::new(1., 0.), Complex::new(0., 0.)]),
+        ];
+        let result = gram_schmidt(&vectors, 1e-10);
+        assert_eq!(result.dependent, vec![1]);
+        assert_orthonormal(&result.basis);
+    }
+
+    #[test]
+    fn gram_schmidt_errors() {
+        let vectors = [Vector::from(&[1., 0.]), Vector::from(&[1., 0., 0.])];
+        assert_eq!(
+            try_gram_schmidt(&vectors, 1e-10).map(|r| r.dependent),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 1),
+                right: (3, 1)
+            })
+        );
+        let empty: [Vector<f64>; 0] = [];
+        let result = gram_schmidt(&empty, 1e-10);
+        assert!(result.basis.is_empty() && result.dependent.is_empty());
+    }
+}
@@ -1,19 +1,33 @@
-use crate::types::Vector;
-use std::ops::Add;
-use std::ops::Mul;
-use std::ops::Sub;
-
-pub fn linear_combination<K>(u: &[Vector<K>], coefs: &[K]) -> Vector<K>
-where
-    K: Add<Output = K> + Mul<Output = K> + Sub<Output = K> + Copy,
-{
+use crate::types::{Scalar, Vector};
+use crate::LinalgError;
+
+pub fn linear_combination<K: Scalar>(u: &[Vector<K>], coefs: &[K]) -> Vector<K> {
+    try_linear_combination(u, coefs)
+        .unwrap_or_else(|e| panic!("Can't compute linear combination: {e}"))
+}
+
+/// Shape errors report the vectors as `(count, 1)` against the coefficients
+/// when their counts differ, and each mismatching vector against the first.
+pub fn try_linear_combination<K: Scalar>(
+    u: &[Vector<K>],
+    coefs: &[K],
+) -> Result<Vector<K>, LinalgError> {
     if u.len() != coefs.len() {
-        panic!("For linear combination you must have same size of vectors and coefs");
+        return Err(LinalgError::ShapeMismatch {
+            left: (u.len(), 1),
+            right: (coefs.len(), 1),
+        });
+    }
+    if u.is_empty() {
+        return Err(LinalgError::Empty);
     }
     let vector_size: usize = u[0].positions.len();
     for elt in u {
         if elt.positions.len() != vector_size {
-            panic!("For linear combination vector must have the same size");
+            return Err(LinalgError::ShapeMismatch {
+                left: (vector_size, 1),
+                right: (elt.size(), 1),
+            });
         }
     }
 
@@ -31,14 +45,13 @@ where
     for i in 1..u.len() {
         let mut scaled_vector = u[i].clone();
         scaled_vector.scl(coefs[i]);
-        scaled_result = scaled_result.clone().add(scaled_vector);
+        scaled_result.add(&scaled_vector);
     }
 
-    scaled_result
+    Ok(scaled_result)
 }
 
 #[cfg(test)]
-
 mod tests {
     use super::*;
 
@@ -89,4 +102,28 @@ mod tests {
         let linear_combined = linear_combination::<f32>(&[v1, v2], &[4., 2.]);
         assert_eq!(linear_combined.positions, &[16., 28.]);
     }
+
+    #[test]
+    fn linear_combination_errors() {
+        let v1 = Vector::from(&[1., 2.]);
+        let v2 = Vector::from(&[1., 2., 3.]);
+        assert_eq!(
+            try_linear_combination(&[v1.clone(), v2.clone()], &[1.]).map(|v| v.positions),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 1),
+                right: (1, 1)
+            })
+        );
+        assert_eq!(
+            try_linear_combination(&[v1, v2], &[1., 2.]).map(|v| v.positions),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 1),
+                right: (3, 1)
+            })
+        );
+        assert_eq!(
+            try_linear_combination::<f32>(&[], &[]).map(|v| v.positions),
+            Err(LinalgError::Empty)
+        );
+    }
 }
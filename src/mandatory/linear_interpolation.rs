@@ -9,7 +9,6 @@ where
 }
 
 #[cfg(test)]
-
 mod tests {
     use super::*;
     use crate::types::{Matrix, Vector};
@@ -23,13 +22,13 @@ mod tests {
     fn linear_interpolation_test_with_floats() {
         assert_eq!(lerp(0., 1., 1.), 1.0);
         assert_eq!(lerp(0., 1., 0.5), 0.5);
-        assert_eq!(lerp(21., 42., 0.3), 27.3);
+        crate::assert_approx_eq!(lerp(21., 42., 0.3), 27.3);
     }
 
     #[test]
     fn linear_interpolation_test_with_vectors() {
         let linear_interpoled = lerp(Vector::from(&[2., 1.]), Vector::from(&[4., 2.]), 0.3);
-        assert_eq!(linear_interpoled.positions, &[2.6, 1.3]);
+        crate::assert_approx_eq!(linear_interpoled, Vector::from(&[2.6, 1.3]));
 
         let linear_interpoled = lerp(Vector::from(&[-42., 42.]), Vector::from(&[42., -42.]), 0.5);
         assert_eq!(linear_interpoled.positions, &[0., 0.]);
@@ -42,8 +41,8 @@ mod tests {
             Matrix::from(&[&[20., 10.], &[30., 40.]]),
             0.5,
         );
-        assert_eq!(linear_interpoled.positions[0], Vec::from([11., 5.5]));
-        assert_eq!(linear_interpoled.positions[1], Vec::from([16.5, 22.]));
+        assert_eq!(linear_interpoled.row(0), Vec::from([11., 5.5]));
+        assert_eq!(linear_interpoled.row(1), Vec::from([16.5, 22.]));
     }
 
     #[test]
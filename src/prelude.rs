@@ -0,0 +1,22 @@
+//! Glob-importable re-exports of the types and functions most code needs.
+
+pub use crate::approx::{Approx, ApproxEq};
+pub use crate::condition::Norm;
+pub use crate::decomposition::cholesky::Cholesky;
+pub use crate::decomposition::eigen::{Eigen, SymmetricEigen};
+pub use crate::decomposition::hessenberg::Hessenberg;
+pub use crate::decomposition::lu::Lu;
+pub use crate::decomposition::qr::Qr;
+pub use crate::decomposition::svd::Svd;
+pub use crate::error::LinalgError;
+pub use crate::least_squares::Regression;
+pub use crate::mandatory::cosine::{angle_cos, try_angle_cos};
+pub use crate::mandatory::cross_product::{cross_product, try_cross_product};
+pub use crate::mandatory::gram_schmidt::{gram_schmidt, try_gram_schmidt, GramSchmidt};
+pub use crate::mandatory::linear_combination::{linear_combination, try_linear_combination};
+pub use crate::mandatory::linear_interpolation::lerp;
+pub use crate::solve::Solution;
+pub use crate::types::{
+    Complex, Echelon, Field, Float, Matrix, Rational, Real, RowOp, RowOpLog, RowOpStep, SMatrix,
+    SVector, Scalar, Tolerance, Vector,
+};
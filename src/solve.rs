@@ -0,0 +1,187 @@
+use crate::types::{Field, Matrix, Scalar, Tolerance, Vector};
+use crate::LinalgError;
+
+/// A solution of `Ax = b` together with whether it is the only one.
+///
+/// When `A` is singular but the system is consistent, `value` is the
+/// particular solution with every free variable set to zero and `unique` is
+/// `false`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solution<T> {
+    pub value: T,
+    pub unique: bool,
+}
+
+impl<K: Field> Matrix<K> {
+    /// Solves `Ax = b` for a square `A`.
+    ///
+    /// Fails with `NotSquare` for a rectangular `A`, `ShapeMismatch` when `b`
+    /// has the wrong length and `Singular` when the system has no solution,
+    /// all at the default tolerance.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Solution<Vector<K>>, LinalgError> {
+        self.solve_with(b, Tolerance::default_for(self.shape()))
+    }
+
+    /// Solves `AX = B` for every column of `B` at once.
+    pub fn solve_many(&self, b: &Matrix<K>) -> Result<Solution<Matrix<K>>, LinalgError> {
+        self.solve_many_with(b, Tolerance::default_for(self.shape()))
+    }
+
+    /// `solve` with pivots within `tolerance` of zero treated as zero, both
+    /// to decide whether `A` is singular and whether the system is
+    /// consistent.
+    pub fn solve_with(
+        &self,
+        b: &Vector<K>,
+        tolerance: Tolerance<K::Real>,
+    ) -> Result<Solution<Vector<K>>, LinalgError> {
+        let rows = self.shape().0;
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        if b.size() != rows {
+            return Err(LinalgError::ShapeMismatch {
+                left: self.shape(),
+                right: (b.size(), 1),
+            });
+        }
+        let b = Matrix::from_vec(rows, 1, b.positions.clone())?;
+        let solution = self.solve_many_with(&b, tolerance)?;
+        Ok(Solution {
+            value: solution.value.column(0),
+            unique: solution.unique,
+        })
+    }
+
+    pub fn solve_many_with(
+        &self,
+        b: &Matrix<K>,
+        tolerance: Tolerance<K::Real>,
+    ) -> Result<Solution<Matrix<K>>, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        if b.shape().0 != self.shape().0 {
+            return Err(LinalgError::ShapeMismatch {
+                left: self.shape(),
+                right: b.shape(),
+            });
+        }
+        let lu = self.lu_with(tolerance)?;
+        if !lu.is_singular() {
+            return Ok(Solution {
+                value: lu.solve_many(b)?,
+                unique: true,
+            });
+        }
+        self.solve_singular(b, tolerance)
+    }
+
+    /// Reads a particular solution off the reduced row echelon form of
+    /// `[A | B]`, or fails if a pivot lands in `B`, i.e. some row reduces to
+    /// `0 = c` with `c != 0`.
+    fn solve_singular(
+        &self,
+        b: &Matrix<K>,
+        tolerance: Tolerance<K::Real>,
+    ) -> Result<Solution<Matrix<K>>, LinalgError> {
+        let (size, _) = self.shape();
+        let rhs_cols = b.shape().1;
+        let mut augmented = Matrix::zeros(size, size + rhs_cols);
+        for row in 0..size {
+            augmented.row_mut(row)[..size].copy_from_slice(self.row(row));
+            augmented.row_mut(row)[size..].copy_from_slice(b.row(row));
+        }
+        // the threshold of A itself, not of [A | B] whose right-hand side
+        // would hide the pivots of a small A
+        let threshold = Tolerance::new(tolerance.threshold(self), K::Real::zero());
+        let rref = augmented.rref_with(threshold);
+
+        let mut result = Matrix::zeros(size, rhs_cols);
+        for &(row, pivot) in rref.pivots() {
+            if pivot >= size {
+                return Err(LinalgError::Singular);
+            }
+            result
+                .row_mut(pivot)
+                .copy_from_slice(&rref.matrix().row(row)[size..]);
+        }
+        Ok(Solution {
+            value: result,
+            unique: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational;
+
+    #[test]
+    fn solve_unique() {
+        let r = Rational::from;
+        let a = Matrix::from(&[&[2., 1., -1.], &[-3., -1., 2.], &[-2., 1., 2.]]);
+        let solution = a.solve(&Vector::from(&[8., -11., -3.])).unwrap();
+        assert!(solution.unique);
+        let expected = [2., 3., -1.];
+        for (value, expected) in solution.value.positions.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+
+        let a = Matrix::from(&[&[r(1), r(2)], &[r(3), r(4)]]);
+        let b = Matrix::from(&[&[r(5), r(1)], &[r(6), r(0)]]);
+        let solution = a.solve_many(&b).unwrap();
+        assert!(solution.unique);
+        assert_eq!(&a * &solution.value, b);
+        assert_eq!(solution.value[(1, 0)], Rational::new(9, 2));
+    }
+
+    #[test]
+    fn solve_singular_consistent() {
+        let r = Rational::from;
+        let a = Matrix::from(&[&[r(1), r(2)], &[r(2), r(4)]]);
+        let b = Vector::from(&[r(3), r(6)]);
+        let solution = a.solve(&b).unwrap();
+        assert!(!solution.unique);
+        assert_eq!(solution.value.positions, vec![r(3), r(0)]);
+        assert_eq!((&a * &solution.value).positions, b.positions);
+    }
+
+    #[test]
+    fn solve_singular_large_rhs() {
+        // the pivots of a tiny A must not be judged against a huge b
+        let a = Matrix::from(&[&[1e-12, 1e-12], &[1e-12, 1e-12]]);
+        let b = Vector::from(&[1e6, 1e6]);
+        let solution = a.solve(&b).unwrap();
+        assert!(!solution.unique);
+        assert_eq!(solution.value.positions, vec![1e18, 0.]);
+        assert_eq!((&a * &solution.value).positions, b.positions);
+    }
+
+    #[test]
+    fn solve_errors() {
+        let a = Matrix::from(&[&[1., 2.], &[2., 4.]]);
+        assert_eq!(
+            a.solve(&Vector::from(&[1., 1.])).map(|s| s.value.positions),
+            Err(LinalgError::Singular)
+        );
+        assert_eq!(
+            a.solve(&Vector::from(&[1., 1., 1.]))
+                .map(|s| s.value.positions),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 2),
+                right: (3, 1)
+            })
+        );
+        let a = Matrix::from(&[&[1., 2., 3.]]);
+        assert_eq!(
+            a.solve_many(&Matrix::zeros(1, 1)),
+            Err(LinalgError::NotSquare { shape: (1, 3) })
+        );
+    }
+}
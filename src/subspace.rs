@@ -0,0 +1,130 @@
+use crate::types::{Field, Matrix, Vector};
+
+/// Bases of the four fundamental subspaces, read off the reduced row echelon
+/// form. Exact for rational scalars; for floating point entries within the
+/// default `Tolerance` of zero get no pivot, as in `rank`.
+impl<K: Field> Matrix<K> {
+    /// Indices of the columns holding a pivot in the reduced row echelon
+    /// form, in increasing order. There are `rank` of them.
+    pub fn pivot_columns(&self) -> Vec<usize> {
+        self.rref().pivot_columns()
+    }
+
+    /// A basis of `{x : Ax = 0}`, one vector per free column: the free
+    /// variable set to one, the others to zero.
+    pub fn null_space(&self) -> Vec<Vector<K>> {
+        let rref = self.rref();
+        let pivots = rref.pivot_columns();
+        let reduced = rref.matrix();
+        let cols = self.shape().1;
+        (0..cols)
+            .filter(|column| !pivots.contains(column))
+            .map(|free| {
+                let mut positions = vec![K::zero(); cols];
+                positions[free] = K::one();
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    positions[pivot] = -reduced[(row, free)];
+                }
+                Vector { positions }
+            })
+            .collect()
+    }
+
+    /// A basis of the span of the columns: the pivot columns of `A` itself.
+    pub fn column_space(&self) -> Vec<Vector<K>> {
+        self.pivot_columns()
+            .into_iter()
+            .map(|column| self.column(column))
+            .collect()
+    }
+
+    /// A basis of the span of the rows: the pivot rows of the reduced row
+    /// echelon form.
+    pub fn row_space(&self) -> Vec<Vector<K>> {
+        let rref = self.rref();
+        rref.matrix()
+            .rows()
+            .take(rref.rank())
+            .map(Vector::from)
+            .collect()
+    }
+
+    /// A basis of `{y : yᴴA = 0}`, the null space of `Aᴴ`.
+    pub fn left_null_space(&self) -> Vec<Vector<K>> {
+        self.conjugate_transpose().null_space()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Rational, Scalar, Vector};
+
+    fn assert_in_null_space<K: Scalar>(a: &Matrix<K>, basis: &[Vector<K>]) {
+        for x in basis {
+            let product = a.try_mul_vec(x).unwrap();
+            assert!(product.positions.iter().all(|value| value.is_zero()));
+        }
+    }
+
+    fn positions(basis: &[Vector<Rational>]) -> Vec<Vec<Rational>> {
+        basis.iter().map(|x| x.positions.clone()).collect()
+    }
+
+    #[test]
+    fn fundamental_subspaces() {
+        let r = Rational::from;
+        let a = Matrix::from(&[
+            &[r(1), r(2), r(0), r(1)],
+            &[r(2), r(4), r(1), r(4)],
+            &[r(3), r(6), r(1), r(5)],
+        ]);
+        assert_eq!(a.pivot_columns(), vec![0, 2]);
+
+        let null = a.null_space();
+        assert_eq!(
+            positions(&null),
+            Matrix::from(&[&[r(-2), r(1), r(0), r(0)], &[r(-1), r(0), r(-2), r(1)]]).to_rows()
+        );
+        assert_in_null_space(&a, &null);
+
+        let columns = a.column_space();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[1].positions, vec![r(0), r(1), r(1)]);
+
+        let rows = a.row_space();
+        assert_eq!(
+            positions(&rows),
+            Matrix::from(&[&[r(1), r(2), r(0), r(1)], &[r(0), r(0), r(1), r(2)]]).to_rows()
+        );
+
+        let left = a.left_null_space();
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].positions, vec![r(-1), r(-1), r(1)]);
+        assert_in_null_space(&a.conjugate_transpose(), &left);
+
+        // rank-nullity on both sides
+        let (m, n) = a.shape();
+        assert_eq!(rows.len() + null.len(), n);
+        assert_eq!(columns.len() + left.len(), m);
+    }
+
+    #[test]
+    fn fundamental_subspaces_edge_cases() {
+        let r = Rational::from;
+        let a = Matrix::from(&[&[Rational::new(1, 2), r(1)], &[r(1), r(2)]]);
+        let null = a.null_space();
+        assert_eq!(null[0].positions, vec![r(-2), Rational::one()]);
+        assert_in_null_space(&a, &null);
+
+        let invertible = Matrix::from(&[&[1., 2.], &[3., 4.]]);
+        assert!(invertible.null_space().is_empty());
+        assert!(invertible.left_null_space().is_empty());
+        assert_eq!(invertible.pivot_columns(), vec![0, 1]);
+
+        let zero: Matrix<f64> = Matrix::zeros(2, 3);
+        assert!(zero.pivot_columns().is_empty());
+        assert!(zero.column_space().is_empty() && zero.row_space().is_empty());
+        assert_eq!(zero.null_space().len(), 3);
+        assert_eq!(zero.left_null_space().len(), 2);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::types::{Field, Real, Scalar};
+use std::fmt;
+
+/// A complex number `re + im·i` over a real scalar type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: Real> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+
+    /// The imaginary unit `i`.
+    pub fn i() -> Self {
+        Complex::new(T::zero(), T::one())
+    }
+
+    pub fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// Squared modulus `re² + im²`, exact for exact `T`.
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn modulus(self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    pub fn scale(self, factor: T) -> Self {
+        Complex::new(self.re * factor, self.im * factor)
+    }
+}
+
+impl<T: Real> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Complex::new(re, T::zero())
+    }
+}
+
+impl<T: Real> std::ops::Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<T: Real> std::ops::Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<T: Real> std::ops::Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<T: Real> std::ops::Div for Complex<T> {
+    type Output = Complex<T>;
+
+    fn div(self, other: Complex<T>) -> Complex<T> {
+        let denominator = other.norm_sqr();
+        let numerator = self * other.conj();
+        Complex::new(numerator.re / denominator, numerator.im / denominator)
+    }
+}
+
+impl<T: Real> std::ops::Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Real> Scalar for Complex<T> {
+    type Real = T;
+
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+
+    fn abs(self) -> T {
+        self.modulus()
+    }
+
+    /// Principal square root, with the branch cut along the negative reals.
+    fn sqrt(self) -> Self {
+        let modulus = self.modulus();
+        let two = T::one() + T::one();
+        let re = ((modulus + self.re) / two).sqrt();
+        let im = ((modulus - self.re) / two).sqrt();
+        if self.im < T::zero() {
+            Complex::new(re, -im)
+        } else {
+            Complex::new(re, im)
+        }
+    }
+
+    fn epsilon() -> T {
+        T::epsilon()
+    }
+
+    fn conj(self) -> Self {
+        Complex::conj(self)
+    }
+
+    fn re(self) -> T {
+        self.re
+    }
+
+    fn from_real(value: T) -> Self {
+        Complex::from(value)
+    }
+}
+
+impl<T: Real + Field> Field for Complex<T> {}
+
+impl<T: Real> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < T::zero() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complex_arithmetic() {
+        let a = Complex::new(1., 2.);
+        let b = Complex::new(3., -1.);
+        assert_eq!(a + b, Complex::new(4., 1.));
+        assert_eq!(a - b, Complex::new(-2., 3.));
+        assert_eq!(a * b, Complex::new(5., 5.));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(-a, Complex::new(-1., -2.));
+        assert_eq!(Complex::<f64>::i() * Complex::i(), Complex::new(-1., 0.));
+    }
+
+    #[test]
+    fn complex_conj_and_modulus() {
+        let a = Complex::new(3., 4.);
+        assert_eq!(a.conj(), Complex::new(3., -4.));
+        assert_eq!(a.norm_sqr(), 25.);
+        assert_eq!(a.modulus(), 5.);
+        assert_eq!(Scalar::abs(a), 5.);
+        assert_eq!(Complex::new(0., 0.).modulus(), 0.);
+        assert_eq!((a * a.conj()).im, 0.);
+    }
+
+    #[test]
+    fn complex_sqrt() {
+        assert_eq!(Scalar::sqrt(Complex::new(-4., 0.)), Complex::new(0., 2.));
+        assert_eq!(Scalar::sqrt(Complex::new(3., 4.)), Complex::new(2., 1.));
+        assert_eq!(Scalar::sqrt(Complex::new(3., -4.)), Complex::new(2., -1.));
+    }
+
+    #[test]
+    fn complex_display() {
+        assert_eq!(Complex::new(1., 2.).to_string(), "1+2i");
+        assert_eq!(Complex::new(1.5, -2.).to_string(), "1.5-2i");
+        assert_eq!(Complex::new(0, 0).to_string(), "0+0i");
+    }
+}
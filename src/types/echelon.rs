@@ -0,0 +1,227 @@
+use crate::types::{Field, Matrix, RowOp, RowOpLog, Scalar, Tolerance};
+use crate::LinalgError;
+
+/// Result of Gaussian elimination with partial pivoting: the echelon form
+/// itself and what it took to get there.
+///
+/// `permutation[i]` is the row of the input that ended up as row `i`, and
+/// `scale` is the factor relating the determinants, `det(A) = scale ·
+/// det(matrix)`: `±1` for `echelon`, where only row swaps change the
+/// determinant, and `±` the product of the pivots for `rref`, which also
+/// normalizes them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Echelon<K> {
+    matrix: Matrix<K>,
+    pivots: Vec<(usize, usize)>,
+    permutation: Vec<usize>,
+    scale: K,
+}
+
+impl<K: Field> Matrix<K> {
+    /// Row echelon form: every pivot is the largest entry of its column at
+    /// or below the current row, and only the entries below it are
+    /// eliminated.
+    pub fn echelon(&self) -> Echelon<K> {
+        self.echelon_with(Tolerance::default_for(self.shape()))
+    }
+
+    /// `echelon` treating entries within `tolerance` of zero as zero when
+    /// looking for pivots.
+    pub fn echelon_with(&self, tolerance: Tolerance<K::Real>) -> Echelon<K> {
+        self.eliminate(false, tolerance, None)
+    }
+
+    /// Reduced row echelon form: pivots are normalized to one and the
+    /// entries above them are eliminated too.
+    pub fn rref(&self) -> Echelon<K> {
+        self.rref_with(Tolerance::default_for(self.shape()))
+    }
+
+    pub fn rref_with(&self, tolerance: Tolerance<K::Real>) -> Echelon<K> {
+        self.eliminate(true, tolerance, None)
+    }
+
+    /// Gaussian elimination, recording every operation that changes the
+    /// matrix in `log` when given one. A column whose remaining entries are
+    /// all within `tolerance` of zero gets no pivot.
+    pub(super) fn eliminate(
+        &self,
+        reduce: bool,
+        tolerance: Tolerance<K::Real>,
+        mut log: Option<&mut RowOpLog<K>>,
+    ) -> Echelon<K> {
+        let (rows, cols) = self.shape();
+        let threshold = tolerance.threshold(self);
+        let mut matrix = self.clone();
+        let mut pivots = Vec::new();
+        let mut permutation: Vec<usize> = (0..rows).collect();
+        let mut scale = K::one();
+        let mut apply = |op: RowOp<K>, matrix: &mut Matrix<K>| {
+            op.apply(matrix);
+            if let Some(log) = log.as_deref_mut() {
+                log.push(op, matrix);
+            }
+        };
+
+        let mut row_index = 0;
+        for column_index in 0..cols {
+            if row_index == rows {
+                break;
+            }
+            let (pivot, pivot_row) = matrix.find_pivot(row_index, column_index, threshold);
+            if pivot.is_zero() {
+                continue;
+            }
+            if reduce {
+                let op = RowOp::Divide {
+                    row: pivot_row,
+                    divisor: pivot,
+                };
+                apply(op, &mut matrix);
+                scale = scale * pivot;
+            }
+            if pivot_row != row_index {
+                apply(RowOp::Swap(row_index, pivot_row), &mut matrix);
+                permutation.swap(row_index, pivot_row);
+                scale = -scale;
+            }
+
+            let others = if reduce { 0 } else { row_index + 1 };
+            for other_row in (others..rows).filter(|&row| row != row_index) {
+                let mut factor = matrix[(other_row, column_index)];
+                if !reduce {
+                    factor = factor / pivot;
+                }
+                if factor.is_zero() {
+                    continue;
+                }
+                let op = RowOp::Subtract {
+                    target: other_row,
+                    source: row_index,
+                    factor,
+                };
+                apply(op, &mut matrix);
+            }
+            pivots.push((row_index, column_index));
+            row_index += 1;
+        }
+        Echelon {
+            matrix,
+            pivots,
+            permutation,
+            scale,
+        }
+    }
+}
+
+impl<K: Scalar> Echelon<K> {
+    pub fn matrix(&self) -> &Matrix<K> {
+        &self.matrix
+    }
+
+    pub fn into_matrix(self) -> Matrix<K> {
+        self.matrix
+    }
+
+    /// `(row, column)` of every pivot, by increasing row.
+    pub fn pivots(&self) -> &[(usize, usize)] {
+        &self.pivots
+    }
+
+    pub fn pivot_columns(&self) -> Vec<usize> {
+        self.pivots.iter().map(|&(_, column)| column).collect()
+    }
+
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn scale(&self) -> K {
+        self.scale
+    }
+
+    /// `scale` times the product of the diagonal of the echelon form, or
+    /// exactly zero when `rank()` falls short of the size.
+    ///
+    /// Unlike `Matrix::determinant`, which is exact and ignores tolerances,
+    /// this treats a matrix that is singular at the elimination tolerance as
+    /// singular, instead of returning whatever rounding residue was left on
+    /// the diagonal.
+    pub fn determinant(&self) -> Result<K, LinalgError> {
+        if !self.matrix.is_square() {
+            return Err(LinalgError::NotSquare {
+                shape: self.matrix.shape(),
+            });
+        }
+        if self.rank() < self.matrix.shape().0 {
+            return Ok(K::zero());
+        }
+        let mut result = self.scale;
+        for i in 0..self.matrix.shape().0 {
+            result = result * self.matrix[(i, i)];
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational;
+
+    #[test]
+    fn echelon_forms() {
+        let a = Matrix::from(&[&[1., 2., 1.], &[2., 2., 2.], &[4., 4., 2.]]);
+        let echelon = a.echelon();
+        assert_eq!(
+            echelon.matrix(),
+            &Matrix::from(&[&[4., 4., 2.], &[0., 1., 0.5], &[0., 0., 1.]])
+        );
+        assert_eq!(echelon.permutation(), [2, 0, 1]);
+        assert_eq!(echelon.pivots(), [(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(echelon.scale(), 1.);
+        assert_eq!(echelon.determinant(), Ok(4.));
+
+        let rref = a.rref();
+        assert_eq!(rref.matrix(), &Matrix::identity(3));
+        assert_eq!(rref.determinant(), Ok(4.));
+        assert_eq!(rref.into_matrix(), a.clone().row_echelon());
+    }
+
+    #[test]
+    fn echelon_rank_deficient() {
+        let r = Rational::from;
+        let a = Matrix::from(&[&[r(1), r(2), r(3)], &[r(2), r(4), r(7)]]);
+        let echelon = a.echelon();
+        assert_eq!(echelon.pivots(), [(0, 0), (1, 2)]);
+        assert_eq!(echelon.pivot_columns(), vec![0, 2]);
+        assert_eq!(echelon.rank(), 2);
+        assert_eq!(
+            echelon.matrix().row(1)[..2],
+            [Rational::zero(), Rational::zero()]
+        );
+        assert_eq!(
+            echelon.determinant(),
+            Err(LinalgError::NotSquare { shape: (2, 3) })
+        );
+        // pivots 2 and -1/2 after one swap
+        assert_eq!(a.rref().scale(), Rational::one());
+
+        let singular = Matrix::from(&[&[1., 2.], &[2., 4.]]);
+        assert_eq!(singular.rref().rank(), 1);
+        assert_eq!(singular.echelon().determinant(), Ok(0.));
+        assert_eq!(singular.rref().determinant(), Ok(0.));
+
+        // rank 2 at the default tolerance, with rounding residue left over
+        let a: Matrix<f64> = Matrix::from(&[&[0.1, 0.2, 0.3], &[0.4, 0.5, 0.6], &[0.7, 0.8, 0.9]]);
+        let echelon = a.echelon();
+        assert_eq!(echelon.rank(), 2);
+        let determinant = echelon.determinant().unwrap();
+        assert_eq!(determinant, 0.);
+        assert!(determinant.is_sign_positive());
+    }
+}
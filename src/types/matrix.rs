@@ -1,467 +1,448 @@
-use crate::Vector;
+use crate::{Field, LinalgError, Scalar, Tolerance, Vector};
 use std::fmt;
 
-#[derive(Clone, Debug)]
+/// A `rows × cols` matrix stored row-major in one contiguous buffer.
+///
+/// Either dimension may be zero; `shape` always returns `(rows, cols)`.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Matrix<K> {
-    pub positions: Vec<Vec<K>>,
+    rows: usize,
+    cols: usize,
+    positions: Vec<K>,
 }
 
-impl<K: std::ops::Add<Output = K> + Copy> std::ops::Add for Matrix<K> {
-    type Output = Matrix<K>;
+impl<K> std::ops::Index<(usize, usize)> for Matrix<K> {
+    type Output = K;
+
+    fn index(&self, (row, column): (usize, usize)) -> &K {
+        assert!(
+            row < self.rows && column < self.cols,
+            "index ({row}, {column}) out of bounds for a {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+        &self.positions[row * self.cols + column]
+    }
+}
 
-    fn add(self, other: Matrix<K>) -> Matrix<K> {
-        let mut result = self;
-        for (row, other_row) in result.positions.iter_mut().zip(other.positions.iter()) {
-            for (column, &other_column) in row.iter_mut().zip(other_row.iter()) {
-                *column = *column + other_column;
-            }
-        }
-        result
+impl<K> std::ops::IndexMut<(usize, usize)> for Matrix<K> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut K {
+        assert!(
+            row < self.rows && column < self.cols,
+            "index ({row}, {column}) out of bounds for a {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+        &mut self.positions[row * self.cols + column]
     }
 }
 
-impl<K: std::ops::Sub<Output = K> + Copy> std::ops::Sub for Matrix<K> {
-    type Output = Matrix<K>;
+impl<K: Scalar> Matrix<K> {
+    /// Builds a matrix from its rows. Panics on ragged input, see
+    /// `try_from_rows`.
+    pub fn from(matrix: &[&[K]]) -> Self {
+        Matrix::try_from_rows(matrix).unwrap_or_else(|e| panic!("Can't build Matrix: {e}"))
+    }
 
-    fn sub(self, other: Matrix<K>) -> Matrix<K> {
-        let mut result = self;
-        for (row, other_row) in result.positions.iter_mut().zip(other.positions.iter()) {
-            for (column, &other_column) in row.iter_mut().zip(other_row.iter()) {
-                *column = *column - other_column;
+    pub fn try_from_rows(matrix: &[&[K]]) -> Result<Self, LinalgError> {
+        let cols = matrix.first().map_or(0, |row| row.len());
+        let mut positions = Vec::with_capacity(matrix.len() * cols);
+        for (index, row) in matrix.iter().enumerate() {
+            if row.len() != cols {
+                return Err(LinalgError::RaggedRows {
+                    row: index,
+                    expected: cols,
+                    found: row.len(),
+                });
             }
+            positions.extend_from_slice(row);
         }
-        result
+        Ok(Matrix {
+            rows: matrix.len(),
+            cols,
+            positions,
+        })
     }
-}
-
-impl<K: std::ops::Mul<f32, Output = K> + Copy> std::ops::Mul<f32> for Matrix<K> {
-    type Output = Matrix<K>;
 
-    fn mul(self, scalar: f32) -> Matrix<K> {
-        let mut result = self;
-        for row in result.positions.iter_mut() {
-            for value in row.iter_mut() {
-                *value = *value * scalar;
-            }
+    /// Wraps a row-major buffer of exactly `rows * cols` entries.
+    pub fn from_vec(rows: usize, cols: usize, positions: Vec<K>) -> Result<Self, LinalgError> {
+        if positions.len() != rows * cols {
+            return Err(LinalgError::ShapeMismatch {
+                left: (rows, cols),
+                right: (positions.len(), 1),
+            });
         }
-        result
+        Ok(Matrix {
+            rows,
+            cols,
+            positions,
+        })
     }
-}
 
-impl<
-        K: Copy
-            + std::ops::Add<Output = K>
-            + std::ops::Sub<Output = K>
-            + std::ops::Mul<Output = K>
-            + std::cmp::PartialOrd,
-    > Matrix<K>
-{
-    fn column_size(&self) -> usize {
-        if self.positions.is_empty() {
-            panic!("Empty matrix");
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows,
+            cols,
+            positions: vec![K::zero(); rows * cols],
         }
-        self.positions[0].len()
     }
 
-    fn row_size(&self) -> usize {
-        self.positions.len()
+    pub fn identity(size: usize) -> Self {
+        let mut result = Matrix::zeros(size, size);
+        for i in 0..size {
+            result[(i, i)] = K::one();
+        }
+        result
     }
 
+    /// `(rows, cols)` of the matrix.
     pub fn shape(&self) -> (usize, usize) {
-        (self.column_size(), self.row_size())
+        (self.rows, self.cols)
     }
 
     pub fn is_square(&self) -> bool {
-        self.positions.len() == self.positions[0].len()
+        self.rows == self.cols
     }
 
-    pub fn from(matrix: &[&[K]]) -> Self {
-        let mut positions = Vec::with_capacity(matrix.len());
-        for row in matrix {
-            positions.push(row.to_vec());
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&K> {
+        if row < self.rows && column < self.cols {
+            self.positions.get(row * self.cols + column)
+        } else {
+            None
         }
-        Matrix { positions }
     }
 
-    fn same_size(&self, v: &Matrix<K>) {
-        if self.positions.len() != v.positions.len() {
-            panic!("Matrix do not have same size!");
+    pub fn row(&self, row: usize) -> &[K] {
+        &self.positions[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [K] {
+        &mut self.positions[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[K]> {
+        (0..self.rows).map(move |row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> Vector<K> {
+        let mut result: Vector<K> = Vector::from(&[]);
+        for row in 0..self.rows {
+            result.positions.push(self[(row, column)]);
         }
-        for (index, point) in self.positions.iter().enumerate() {
-            if point.len() != v.positions[index].len() {
-                panic!("Matrix rows do not have same size!");
-            }
+        result
+    }
+
+    /// The row-major buffer.
+    pub fn as_slice(&self) -> &[K] {
+        &self.positions
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [K] {
+        &mut self.positions
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<K>> {
+        self.rows().map(|row| row.to_vec()).collect()
+    }
+
+    pub(super) fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for column in 0..self.cols {
+            self.positions
+                .swap(a * self.cols + column, b * self.cols + column);
         }
     }
 
+    fn same_size(&self, v: &Matrix<K>) -> Result<(), LinalgError> {
+        if self.shape() != v.shape() {
+            return Err(LinalgError::ShapeMismatch {
+                left: self.shape(),
+                right: v.shape(),
+            });
+        }
+        Ok(())
+    }
+
     pub fn add(&mut self, v: &Matrix<K>) {
-        self.same_size(v);
-        for (row_index, row) in v.positions.iter().enumerate() {
-            for (col_index, column) in row.iter().enumerate() {
-                self.positions[row_index][col_index] =
-                    self.positions[row_index][col_index] + *column;
-            }
+        self.try_add(v)
+            .unwrap_or_else(|e| panic!("Can't add Matrix: {e}"));
+    }
+
+    pub fn try_add(&mut self, v: &Matrix<K>) -> Result<(), LinalgError> {
+        self.same_size(v)?;
+        for (point, &other_point) in self.positions.iter_mut().zip(v.positions.iter()) {
+            *point = *point + other_point;
         }
+        Ok(())
     }
 
     pub fn sub(&mut self, v: &Matrix<K>) {
-        self.same_size(v);
-        for (row_index, row) in v.positions.iter().enumerate() {
-            for (col_index, column) in row.iter().enumerate() {
-                self.positions[row_index][col_index] =
-                    self.positions[row_index][col_index] - *column;
-            }
+        self.try_sub(v)
+            .unwrap_or_else(|e| panic!("Can't subtract Matrix: {e}"));
+    }
+
+    pub fn try_sub(&mut self, v: &Matrix<K>) -> Result<(), LinalgError> {
+        self.same_size(v)?;
+        for (point, &other_point) in self.positions.iter_mut().zip(v.positions.iter()) {
+            *point = *point - other_point;
         }
+        Ok(())
     }
 
     pub fn scl(&mut self, a: K) {
-        for row in self.positions.iter_mut() {
-            for column in row.iter_mut() {
-                *column = *column * a;
-            }
+        for point in self.positions.iter_mut() {
+            *point = *point * a;
         }
     }
 
     pub fn mul_vec(&mut self, vec: Vector<K>) -> Vector<K> {
-        if self.column_size() != vec.size() {
-            panic!("The number of columns in this Matrix must equals the number of rows in vec");
+        self.try_mul_vec(&vec)
+            .unwrap_or_else(|e| panic!("Can't multiply Matrix by Vector: {e}"))
+    }
+
+    pub fn try_mul_vec(&self, vec: &Vector<K>) -> Result<Vector<K>, LinalgError> {
+        if self.cols != vec.size() {
+            return Err(LinalgError::ShapeMismatch {
+                left: self.shape(),
+                right: (vec.size(), 1),
+            });
         }
         let mut result: Vector<K> = Vector::from(&[]);
 
-        for row in self.positions.iter() {
-            let row_to_vector: Vector<K> = Vector::from(row);
-            result.positions.push(row_to_vector.dot(vec.clone()));
+        for row in self.rows() {
+            let mut product = K::zero();
+            for (point, &other_point) in row.iter().zip(vec.positions.iter()) {
+                product = product + *point * other_point;
+            }
+            result.positions.push(product);
         }
-        result
+        Ok(result)
     }
 
     pub fn mul_mat(&mut self, mat: Matrix<K>) -> Matrix<K> {
-        if self.row_size() != mat.column_size() {
-            panic!("The number of columns in this Matrix must equals the number of rows in mat");
-        }
-        let mut mat_rotated: Matrix<K> = Matrix::from(&[]);
-        for column in 0..mat.column_size() {
-            let mut mat_rotated_row: Vec<K> = Vec::new();
-            for row in 0..mat.row_size() {
-                mat_rotated_row.push(mat.positions[row][column]);
-            }
-            mat_rotated.positions.push(mat_rotated_row);
-        }
+        self.try_mul_mat(&mat)
+            .unwrap_or_else(|e| panic!("Can't multiply Matrix: {e}"))
+    }
 
-        let mut result: Matrix<K> = Matrix::from(&[]);
-        for row in self.clone().positions {
-            let mut result_row: Vec<K> = Vec::new();
-            for rotated_mat_row in mat_rotated.clone().positions {
-                let rotated_mat_to_vec: Vector<K> = Vector::from(&rotated_mat_row);
-                let product: K = Vector::from(&row).dot(rotated_mat_to_vec);
-                result_row.push(product);
+    pub fn try_mul_mat(&self, mat: &Matrix<K>) -> Result<Matrix<K>, LinalgError> {
+        if self.cols != mat.rows {
+            return Err(LinalgError::ShapeMismatch {
+                left: self.shape(),
+                right: mat.shape(),
+            });
+        }
+        let mut result: Matrix<K> = Matrix::zeros(self.rows, mat.cols);
+        for row in 0..self.rows {
+            for inner in 0..self.cols {
+                let factor = self[(row, inner)];
+                for column in 0..mat.cols {
+                    result[(row, column)] = result[(row, column)] + factor * mat[(inner, column)];
+                }
             }
-            result.positions.push(result_row);
         }
-        result
+        Ok(result)
     }
 
+    /// Sum of the main diagonal, zero for an empty matrix.
     pub fn trace(&mut self) -> K {
-        if self.shape() == (0, 0) {
-            panic!("Can't trace an empty matrix");
-        }
-        let mut result: K = self.positions[0][0];
-        let mut col_index: usize = 1;
-        let mut row_index: usize = 1;
-        while col_index < self.column_size() && row_index < self.row_size() {
-            result = result + self.positions[row_index][col_index];
-            col_index += 1;
-            row_index += 1;
+        let mut result: K = K::zero();
+        for index in 0..self.rows.min(self.cols) {
+            result = result + self[(index, index)];
         }
-
         result
     }
 
     pub fn transpose(&mut self) -> Matrix<K> {
-        let mut mat_rotated: Matrix<K> = Matrix::from(&[]);
-        for column in 0..self.column_size() {
-            let mut mat_rotated_row: Vec<K> = Vec::new();
-            for row in 0..self.row_size() {
-                mat_rotated_row.push(self.positions[row][column]);
+        let mut mat_rotated: Matrix<K> = Matrix::zeros(self.cols, self.rows);
+        for row in 0..self.rows {
+            for column in 0..self.cols {
+                mat_rotated[(column, row)] = self[(row, column)];
             }
-            mat_rotated.positions.push(mat_rotated_row);
         }
         mat_rotated
     }
 
-    fn find_pivot(&mut self, row: usize, column: usize) -> (K, usize)
-    where
-        K: PartialEq + Default + std::ops::Neg<Output = K> + PartialOrd,
-    {
-        let zero: K = K::default();
-        let mut max: K = K::default();
-        let mut max_abs: K = if max < zero { -max } else { max };
+    /// Transpose with every entry conjugated, the adjoint `Aᴴ`. Identical to
+    /// `transpose` for real matrices.
+    pub fn conjugate_transpose(&self) -> Matrix<K> {
+        let mut result: Matrix<K> = Matrix::zeros(self.cols, self.rows);
+        for row in 0..self.rows {
+            for column in 0..self.cols {
+                result[(column, row)] = self[(row, column)].conj();
+            }
+        }
+        result
+    }
+
+    /// Largest entry of `column` from `row` down, ignoring entries whose
+    /// magnitude is at most `threshold`. Returns zero when there is none.
+    pub(super) fn find_pivot(&self, row: usize, column: usize, threshold: K::Real) -> (K, usize) {
+        let mut max: K = K::zero();
+        let mut max_abs: K::Real = threshold;
         let mut max_row: usize = row;
 
-        for row_index in row..self.row_size() {
-            let mut point = self.positions[row_index][column];
-            if point < zero {
-                point = -point;
-            }
-            if self.positions[row_index][column] != zero && point > max_abs {
-                max = self.positions[row_index][column];
+        for row_index in row..self.rows {
+            let point = self[(row_index, column)];
+            if !point.is_zero() && point.abs() > max_abs {
+                max = point;
                 max_row = row_index;
-                max_abs = if max < zero { -max } else { max };
+                max_abs = max.abs();
             }
         }
         (max, max_row)
     }
 
-    pub fn row_echelon(&mut self) -> Matrix<K>
-    where
-        K: Copy + PartialEq + Default + std::ops::Div<Output = K> + std::ops::Neg<Output = K>,
-    {
-        let mut row_echelon_form: Matrix<K> = self.clone();
-        let zero = K::default();
-
-        let mut row_index: usize = 0;
-        let mut column_index: usize = 0;
-
-        while column_index < row_echelon_form.column_size()
-            && row_index < row_echelon_form.row_size()
-        {
-            let (pivot, pivot_row) = row_echelon_form.find_pivot(row_index, column_index);
-
-            if pivot != zero {
-                for i in 0..row_echelon_form.column_size() {
-                    row_echelon_form.positions[pivot_row][i] =
-                        row_echelon_form.positions[pivot_row][i] / pivot;
-                }
-
-                if pivot_row != row_index {
-                    row_echelon_form.positions.swap(row_index, pivot_row);
-                }
-
-                let mut row_below_pivot_index = 0;
-
-                while row_below_pivot_index < row_echelon_form.row_size() {
-                    if row_below_pivot_index != row_index {
-                        let mut scaled_row: Vector<K> =
-                            Vector::from(&row_echelon_form.positions[row_index]);
-                        scaled_row
-                            .scl(row_echelon_form.positions[row_below_pivot_index][column_index]);
-
-                        let mut row_below_pivot =
-                            Vector::from(&row_echelon_form.positions[row_below_pivot_index]);
-                        row_below_pivot.sub(&scaled_row);
-
-                        row_echelon_form.positions[row_below_pivot_index] =
-                            row_below_pivot.positions;
-                    }
-                    row_below_pivot_index += 1;
-                }
-                row_index += 1;
+    /// Matrix left after removing `row` and `column`.
+    fn minor(&self, row: usize, column: usize) -> Matrix<K> {
+        let mut positions = Vec::with_capacity((self.rows - 1) * (self.cols - 1));
+        for i in (0..self.rows).filter(|&i| i != row) {
+            for j in (0..self.cols).filter(|&j| j != column) {
+                positions.push(self[(i, j)]);
             }
-            column_index += 1;
         }
-        row_echelon_form
+        Matrix {
+            rows: self.rows - 1,
+            cols: self.cols - 1,
+            positions,
+        }
     }
 
     fn determinant_2(&mut self) -> K {
-        (self.positions[0][0] * self.positions[1][1])
-            - (self.positions[0][1] * self.positions[1][0])
+        (self[(0, 0)] * self[(1, 1)]) - (self[(0, 1)] * self[(1, 0)])
     }
 
     fn determinant_3(&mut self) -> K {
-        let a: K = self.positions[0][0]
-            * Matrix::from(&[
-                &[self.positions[1][1], self.positions[1][2]],
-                &[self.positions[2][1], self.positions[2][2]],
-            ])
-            .determinant_2();
-        let b: K = self.positions[0][1]
-            * Matrix::from(&[
-                &[self.positions[1][0], self.positions[1][2]],
-                &[self.positions[2][0], self.positions[2][2]],
-            ])
-            .determinant_2();
-        let c: K = self.positions[0][2]
-            * Matrix::from(&[
-                &[self.positions[1][0], self.positions[1][1]],
-                &[self.positions[2][0], self.positions[2][1]],
-            ])
-            .determinant_2();
+        let a: K = self[(0, 0)] * self.minor(0, 0).determinant_2();
+        let b: K = self[(0, 1)] * self.minor(0, 1).determinant_2();
+        let c: K = self[(0, 2)] * self.minor(0, 2).determinant_2();
         a - b + c
     }
 
     fn determinant_4(&mut self) -> K {
-        let a: K = self.positions[0][0]
-            * Matrix::from(&[
-                &[
-                    self.positions[1][1],
-                    self.positions[1][2],
-                    self.positions[1][3],
-                ],
-                &[
-                    self.positions[2][1],
-                    self.positions[2][2],
-                    self.positions[2][3],
-                ],
-                &[
-                    self.positions[3][1],
-                    self.positions[3][2],
-                    self.positions[3][3],
-                ],
-            ])
-            .determinant_3();
-
-        let b: K = self.positions[0][1]
-            * Matrix::from(&[
-                &[
-                    self.positions[1][0],
-                    self.positions[1][2],
-                    self.positions[1][3],
-                ],
-                &[
-                    self.positions[2][0],
-                    self.positions[2][2],
-                    self.positions[2][3],
-                ],
-                &[
-                    self.positions[3][0],
-                    self.positions[3][2],
-                    self.positions[3][3],
-                ],
-            ])
-            .determinant_3();
-
-        let c: K = self.positions[0][2]
-            * Matrix::from(&[
-                &[
-                    self.positions[1][0],
-                    self.positions[1][1],
-                    self.positions[1][3],
-                ],
-                &[
-                    self.positions[2][0],
-                    self.positions[2][1],
-                    self.positions[2][3],
-                ],
-                &[
-                    self.positions[3][0],
-                    self.positions[3][1],
-                    self.positions[3][3],
-                ],
-            ])
-            .determinant_3();
-
-        let d: K = self.positions[0][3]
-            * Matrix::from(&[
-                &[
-                    self.positions[1][0],
-                    self.positions[1][1],
-                    self.positions[1][2],
-                ],
-                &[
-                    self.positions[2][0],
-                    self.positions[2][1],
-                    self.positions[2][2],
-                ],
-                &[
-                    self.positions[3][0],
-                    self.positions[3][1],
-                    self.positions[3][2],
-                ],
-            ])
-            .determinant_3();
+        let a: K = self[(0, 0)] * self.minor(0, 0).determinant_3();
+        let b: K = self[(0, 1)] * self.minor(0, 1).determinant_3();
+        let c: K = self[(0, 2)] * self.minor(0, 2).determinant_3();
+        let d: K = self[(0, 3)] * self.minor(0, 3).determinant_3();
         a - b + c - d
     }
 
-    pub fn determinant(&mut self) -> K
-    where
-        K: Default,
-    {
-        if !self.is_square() {
-            panic!("The matrix must be square to compute is determinant");
+    /// Fraction-free (Bareiss) elimination in O(n³). Every division is
+    /// exact, so integer and rational determinants stay exact.
+    fn determinant_bareiss(&self) -> K {
+        let size = self.rows;
+        let mut matrix = self.clone();
+        let mut sign = K::one();
+        let mut previous_pivot = K::one();
+
+        for k in 0..size - 1 {
+            let (pivot, pivot_row) = matrix.find_pivot(k, k, K::Real::zero());
+            if pivot.is_zero() {
+                return K::zero();
+            }
+            if pivot_row != k {
+                matrix.swap_rows(k, pivot_row);
+                sign = -sign;
+            }
+            for i in k + 1..size {
+                for j in k + 1..size {
+                    matrix[(i, j)] = (matrix[(i, j)] * matrix[(k, k)]
+                        - matrix[(i, k)] * matrix[(k, j)])
+                        / previous_pivot;
+                }
+            }
+            previous_pivot = matrix[(k, k)];
         }
+        sign * matrix[(size - 1, size - 1)]
+    }
 
-        if self.row_size() == 0 || self.column_size() == 0 {
-            return K::default();
-        }
+    pub fn determinant(&mut self) -> K {
+        self.try_determinant()
+            .unwrap_or_else(|e| panic!("Can't compute determinant: {e}"))
+    }
 
-        if self.row_size() == 1 && self.column_size() == 1 {
-            return self.positions[0][0];
-        }
-        if self.row_size() == 2 {
-            self.determinant_2()
-        } else if self.row_size() == 3 {
-            return self.determinant_3();
-        } else if self.row_size() == 4 {
-            return self.determinant_4();
-        } else {
-            panic!("Matrix determinant are available only for matrix of n <= 4 && n >= 2");
+    /// The determinant of the 0x0 matrix is one, the empty product. Sizes up
+    /// to 4 use cofactor expansion, larger ones Bareiss elimination.
+    pub fn try_determinant(&mut self) -> Result<K, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare {
+                shape: self.shape(),
+            });
         }
-    }
 
-    pub fn rank(&mut self) -> usize
-    where
-        K: PartialEq
-            + Copy
-            + Default
-            + std::ops::Div<Output = K>
-            + std::ops::Neg<Output = K>
-            + std::fmt::Display,
-    {
-        let rref = self.row_echelon();
-        let mut rank_value: usize = 0;
-
-        for (row_index, row) in rref.positions.iter().enumerate() {
-            let first = rref.positions[row_index][0];
-            if !row
-                .iter()
-                .all(|&item| item == first && item == K::default())
-            {
-                rank_value += 1;
-            }
+        match self.rows {
+            0 => Ok(K::one()),
+            1 => Ok(self[(0, 0)]),
+            2 => Ok(self.determinant_2()),
+            3 => Ok(self.determinant_3()),
+            4 => Ok(self.determinant_4()),
+            _ => Ok(self.determinant_bareiss()),
         }
-        rank_value
     }
 }
 
-impl Matrix<f32> {
-    fn identity_matrix(&mut self) -> Matrix<f32> {
-        let mut result: Matrix<f32> = Matrix::from(&[]);
-        for i in 0..self.positions.len() {
-            let mut row = vec![0.0; self.positions.len()];
-            row[i] = 1.0;
-            result.positions.push(row);
-        }
-        result
+impl<K: Field> Matrix<K> {
+    /// Reduced row echelon form, see `rref` for the pivots and row
+    /// permutation that go with it.
+    pub fn row_echelon(&mut self) -> Matrix<K> {
+        self.rref().into_matrix()
+    }
+
+    /// Number of pivots found by `rref` at the default tolerance.
+    pub fn rank(&mut self) -> usize {
+        self.rref().rank()
+    }
+
+    pub fn rank_with(&self, tolerance: Tolerance<K::Real>) -> usize {
+        self.rref_with(tolerance).rank()
+    }
+
+    fn identity_matrix(&self) -> Matrix<K> {
+        Matrix::identity(self.rows)
     }
 
-    pub fn inverse(&mut self) -> Result<Matrix<f32>, String> {
+    /// Fails with `Singular` when the rank at the default tolerance is not
+    /// full, so a matrix singular up to rounding is rejected.
+    pub fn inverse(&mut self) -> Result<Matrix<K>, LinalgError> {
+        self.inverse_with(Tolerance::default_for(self.shape()))
+    }
+
+    /// Gauss–Jordan on `[A | I]`: one elimination both inverts `A` and,
+    /// when a pivot is missing from the `A` half, finds it singular.
+    pub fn inverse_with(&self, tolerance: Tolerance<K::Real>) -> Result<Matrix<K>, LinalgError> {
         if !self.is_square() {
-            return Err(String::from("Matrix is not square"));
-        }
-        let rank = self.rank();
-        if rank < self.column_size() || rank < self.row_size() {
-            return Err(String::from("Matrix is singular"));
-        }
-        if self.row_size() == 2 && self.column_size() == 2 {
-            let determinant = self.determinant();
-            let mut result = self.clone();
-            result.positions[0][1] = -result.positions[0][1];
-            result.positions[1][0] = -result.positions[1][0];
-            let tmp = result.positions[0][0];
-            result.positions[0][0] = result.positions[1][1];
-            result.positions[1][1] = tmp;
-            result.scl(1. / determinant);
-            return Ok(result);
+            return Err(LinalgError::NotSquare {
+                shape: self.shape(),
+            });
         }
-
         let identity_matrix = self.identity_matrix();
-        let mut augmented_matrix = self.clone();
-        for (index, row) in identity_matrix.positions.iter().enumerate() {
-            augmented_matrix.positions[index].extend(row.iter().cloned());
+        let size = self.rows;
+        let mut augmented_matrix = Matrix::zeros(size, 2 * size);
+        for row in 0..size {
+            augmented_matrix.row_mut(row)[..size].copy_from_slice(self.row(row));
+            augmented_matrix.row_mut(row)[size..].copy_from_slice(identity_matrix.row(row));
+        }
+        // the threshold of A itself, not of [A | I] whose identity half
+        // would swamp a small A
+        let threshold = Tolerance::new(tolerance.threshold(self), K::Real::zero());
+        let reduced = augmented_matrix.rref_with(threshold);
+        let rank = reduced
+            .pivots()
+            .iter()
+            .filter(|&&(_, column)| column < size);
+        if rank.count() < size {
+            return Err(LinalgError::Singular);
         }
-        let mut result = augmented_matrix.row_echelon();
-        for index in 0..self.row_size() {
-            result.positions[index].drain(0..self.column_size());
+        let mut result = Matrix::zeros(size, size);
+        for row in 0..size {
+            result
+                .row_mut(row)
+                .copy_from_slice(&reduced.matrix().row(row)[size..]);
         }
         Ok(result)
     }
@@ -470,13 +451,14 @@ impl Matrix<f32> {
 impl<K: fmt::Display> fmt::Display for Matrix<K> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "[").expect("can't write in stdout");
-        for row in self.positions.iter() {
+        for row in 0..self.rows {
             write!(f, "[").expect("can't write in stdout");
-            for (index, point) in row.iter().enumerate() {
-                write!(f, "{}", point).expect("can't write in stdout");
-                if index < row.len() - 1 {
+            for column in 0..self.cols {
+                if column != 0 {
                     write!(f, ",").expect("can't write in stdout");
                 }
+                write!(f, "{}", self.positions[row * self.cols + column])
+                    .expect("can't write in stdout");
             }
             writeln!(f, "]").expect("can't write in stdout");
         }
@@ -487,38 +469,39 @@ impl<K: fmt::Display> fmt::Display for Matrix<K> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Complex, Rational};
 
     #[test]
     fn matrix_add() {
         let mut u = Matrix::from(&[&[1., 2.], &[3., 4.]]);
         let v = Matrix::from(&[&[7., 4.], &[-2., 2.]]);
         u.add(&v);
-        assert_eq!(Vec::from([8.0, 6.0]), u.positions[0]);
-        assert_eq!(Vec::from([1.0, 6.0]), u.positions[1]);
+        assert_eq!(Vec::from([8.0, 6.0]), u.row(0));
+        assert_eq!(Vec::from([1.0, 6.0]), u.row(1));
 
         let mut u = Matrix::from(&[&[0, 0], &[0, 0]]);
         let v = Matrix::from(&[&[0, 0], &[0, 0]]);
         u.add(&v);
-        assert_eq!(Vec::from([0, 0]), u.positions[0]);
-        assert_eq!(Vec::from([0, 0]), u.positions[1]);
+        assert_eq!(Vec::from([0, 0]), u.row(0));
+        assert_eq!(Vec::from([0, 0]), u.row(1));
 
         let mut u = Matrix::from(&[&[1, 0], &[0, 1]]);
         let v = Matrix::from(&[&[0, 0], &[0, 0]]);
         u.add(&v);
-        assert_eq!(Vec::from([1, 0]), u.positions[0]);
-        assert_eq!(Vec::from([0, 1]), u.positions[1]);
+        assert_eq!(Vec::from([1, 0]), u.row(0));
+        assert_eq!(Vec::from([0, 1]), u.row(1));
 
         let mut u = Matrix::from(&[&[1, 1], &[1, 1]]);
         let v = Matrix::from(&[&[1, 1], &[1, 1]]);
         u.add(&v);
-        assert_eq!(Vec::from([2, 2]), u.positions[0]);
-        assert_eq!(Vec::from([2, 2]), u.positions[1]);
+        assert_eq!(Vec::from([2, 2]), u.row(0));
+        assert_eq!(Vec::from([2, 2]), u.row(1));
 
         let mut u = Matrix::from(&[&[21, 21], &[21, 21]]);
         let v = Matrix::from(&[&[21, 21], &[21, 21]]);
         u.add(&v);
-        assert_eq!(Vec::from([42, 42]), u.positions[0]);
-        assert_eq!(Vec::from([42, 42]), u.positions[1]);
+        assert_eq!(Vec::from([42, 42]), u.row(0));
+        assert_eq!(Vec::from([42, 42]), u.row(1));
     }
 
     #[test]
@@ -526,55 +509,55 @@ mod tests {
         let mut u = Matrix::from(&[&[1., 2.], &[3., 4.]]);
         let v = Matrix::from(&[&[7., 4.], &[-2., 2.]]);
         u.sub(&v);
-        assert_eq!(Vec::from([-6.0, -2.0]), u.positions[0]);
-        assert_eq!(Vec::from([5.0, 2.0]), u.positions[1]);
+        assert_eq!(Vec::from([-6.0, -2.0]), u.row(0));
+        assert_eq!(Vec::from([5.0, 2.0]), u.row(1));
 
         let mut u = Matrix::from(&[&[0, 0], &[0, 0]]);
         let v = Matrix::from(&[&[0, 0], &[0, 0]]);
         u.sub(&v);
-        assert_eq!(Vec::from([0, 0]), u.positions[0]);
-        assert_eq!(Vec::from([0, 0]), u.positions[1]);
+        assert_eq!(Vec::from([0, 0]), u.row(0));
+        assert_eq!(Vec::from([0, 0]), u.row(1));
 
         let mut u = Matrix::from(&[&[1, 0], &[0, 1]]);
         let v = Matrix::from(&[&[0, 0], &[0, 0]]);
         u.sub(&v);
-        assert_eq!(Vec::from([1, 0]), u.positions[0]);
-        assert_eq!(Vec::from([0, 1]), u.positions[1]);
+        assert_eq!(Vec::from([1, 0]), u.row(0));
+        assert_eq!(Vec::from([0, 1]), u.row(1));
 
         let mut u = Matrix::from(&[&[1, 1], &[1, 1]]);
         let v = Matrix::from(&[&[1, 1], &[1, 1]]);
         u.sub(&v);
-        assert_eq!(Vec::from([0, 0]), u.positions[0]);
-        assert_eq!(Vec::from([0, 0]), u.positions[1]);
+        assert_eq!(Vec::from([0, 0]), u.row(0));
+        assert_eq!(Vec::from([0, 0]), u.row(1));
 
         let mut u = Matrix::from(&[&[21, 21], &[21, 21]]);
         let v = Matrix::from(&[&[21, 21], &[21, 21]]);
         u.sub(&v);
-        assert_eq!(Vec::from([0, 0]), u.positions[0]);
-        assert_eq!(Vec::from([0, 0]), u.positions[1]);
+        assert_eq!(Vec::from([0, 0]), u.row(0));
+        assert_eq!(Vec::from([0, 0]), u.row(1));
     }
 
     #[test]
     fn matrix_scale() {
         let mut u = Matrix::from(&[&[1., 2.], &[3., 4.]]);
         u.scl(2.);
-        assert_eq!(Vec::from([2.0, 4.0]), u.positions[0]);
-        assert_eq!(Vec::from([6.0, 8.0]), u.positions[1]);
+        assert_eq!(Vec::from([2.0, 4.0]), u.row(0));
+        assert_eq!(Vec::from([6.0, 8.0]), u.row(1));
 
         let mut u = Matrix::from(&[&[1, 0], &[0, 1]]);
         u.scl(1);
-        assert_eq!(Vec::from([1, 0]), u.positions[0]);
-        assert_eq!(Vec::from([0, 1]), u.positions[1]);
+        assert_eq!(Vec::from([1, 0]), u.row(0));
+        assert_eq!(Vec::from([0, 1]), u.row(1));
 
         let mut u = Matrix::from(&[&[1, 2], &[3, 4]]);
         u.scl(2);
-        assert_eq!(Vec::from([2, 4]), u.positions[0]);
-        assert_eq!(Vec::from([6, 8]), u.positions[1]);
+        assert_eq!(Vec::from([2, 4]), u.row(0));
+        assert_eq!(Vec::from([6, 8]), u.row(1));
 
         let mut u = Matrix::from(&[&[21., 21.], &[21., 21.]]);
         u.scl(0.5);
-        assert_eq!(Vec::from([10.5, 10.5]), u.positions[0]);
-        assert_eq!(Vec::from([10.5, 10.5]), u.positions[1]);
+        assert_eq!(Vec::from([10.5, 10.5]), u.row(0));
+        assert_eq!(Vec::from([10.5, 10.5]), u.row(1));
     }
 
     #[test]
@@ -621,26 +604,26 @@ mod tests {
         let mut u = Matrix::from(&[&[1., 0.], &[0., 1.]]);
         let v = Matrix::from(&[&[1., 0.], &[0., 1.]]);
         let result = u.mul_mat(v);
-        assert_eq!(result.positions[0], Vec::from([1., 0.]));
-        assert_eq!(result.positions[1], Vec::from([0., 1.]));
+        assert_eq!(result.row(0), Vec::from([1., 0.]));
+        assert_eq!(result.row(1), Vec::from([0., 1.]));
 
         let mut u = Matrix::from(&[&[1., 0.], &[0., 1.]]);
         let v = Matrix::from(&[&[2., 1.], &[4., 2.]]);
         let result = u.mul_mat(v);
-        assert_eq!(result.positions[0], Vec::from([2., 1.]));
-        assert_eq!(result.positions[1], Vec::from([4., 2.]));
+        assert_eq!(result.row(0), Vec::from([2., 1.]));
+        assert_eq!(result.row(1), Vec::from([4., 2.]));
 
         let mut u = Matrix::from(&[&[3., -5.], &[6., 8.]]);
         let v = Matrix::from(&[&[2., 1.], &[4., 2.]]);
         let result = u.mul_mat(v);
-        assert_eq!(result.positions[0], Vec::from([-14., -7.]));
-        assert_eq!(result.positions[1], Vec::from([44., 22.]));
+        assert_eq!(result.row(0), Vec::from([-14., -7.]));
+        assert_eq!(result.row(1), Vec::from([44., 22.]));
 
         let mut u = Matrix::from(&[&[0., 4., -2.], &[-4., -3., 0.]]);
         let v = Matrix::from(&[&[0., 1.], &[1., -1.], &[2., 3.]]);
         let result = u.mul_mat(v);
-        assert_eq!(result.positions[0], Vec::from([0., -10.]));
-        assert_eq!(result.positions[1], Vec::from([-3., -1.]));
+        assert_eq!(result.row(0), Vec::from([0., -10.]));
+        assert_eq!(result.row(1), Vec::from([-3., -1.]));
     }
 
     #[test]
@@ -674,112 +657,129 @@ mod tests {
     fn matrix_transpose_zero() {
         let mut u = Matrix::from(&[&[0., 0.], &[0., 0.]]);
         let result = u.transpose();
-        assert_eq!(result.positions[0], Vec::from([0., 0.]));
-        assert_eq!(result.positions[1], Vec::from([0., 0.]));
+        assert_eq!(result.row(0), Vec::from([0., 0.]));
+        assert_eq!(result.row(1), Vec::from([0., 0.]));
     }
 
     #[test]
     fn matrix_transpose_no_change() {
         let mut u = Matrix::from(&[&[1., 0.], &[0., 1.]]);
         let result = u.transpose();
-        assert_eq!(result.positions[0], Vec::from([1., 0.]));
-        assert_eq!(result.positions[1], Vec::from([0., 1.]));
+        assert_eq!(result.row(0), Vec::from([1., 0.]));
+        assert_eq!(result.row(1), Vec::from([0., 1.]));
     }
 
     #[test]
     fn matrix_transpose_reverse() {
         let mut u = Matrix::from(&[&[1., 2.], &[3., 4.]]);
         let result = u.transpose();
-        assert_eq!(result.positions[0], Vec::from([1., 3.]));
-        assert_eq!(result.positions[1], Vec::from([2., 4.]));
+        assert_eq!(result.row(0), Vec::from([1., 3.]));
+        assert_eq!(result.row(1), Vec::from([2., 4.]));
     }
 
     #[test]
     fn matrix_transpose_bin_no_change() {
         let mut u = Matrix::from(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., 1.]]);
         let result = u.transpose();
-        assert_eq!(result.positions[0], Vec::from([1., 0., 0.]));
-        assert_eq!(result.positions[1], Vec::from([0., 1., 0.]));
-        assert_eq!(result.positions[2], Vec::from([0., 0., 1.]));
+        assert_eq!(result.row(0), Vec::from([1., 0., 0.]));
+        assert_eq!(result.row(1), Vec::from([0., 1., 0.]));
+        assert_eq!(result.row(2), Vec::from([0., 0., 1.]));
     }
 
     #[test]
     fn matrix_transpose_positive() {
         let mut u = Matrix::from(&[&[1., 2., 3.], &[4., 5., 6.]]);
         let result = u.transpose();
-        assert_eq!(result.positions[0], Vec::from([1., 4.]));
-        assert_eq!(result.positions[1], Vec::from([2., 5.]));
-        assert_eq!(result.positions[2], Vec::from([3., 6.]));
+        assert_eq!(result.row(0), Vec::from([1., 4.]));
+        assert_eq!(result.row(1), Vec::from([2., 5.]));
+        assert_eq!(result.row(2), Vec::from([3., 6.]));
 
         let mut u = Matrix::from(&[&[1., 2.], &[3., 4.], &[5., 6.]]);
         let result = u.transpose();
-        assert_eq!(result.positions[0], Vec::from([1., 3., 5.]));
-        assert_eq!(result.positions[1], Vec::from([2., 4., 6.]));
+        assert_eq!(result.row(0), Vec::from([1., 3., 5.]));
+        assert_eq!(result.row(1), Vec::from([2., 4., 6.]));
     }
 
     #[test]
     fn matrix_rref_basics() {
-        let mut u = Matrix::from(&[&[1, -1, 2], &[3, 2, 1], &[2, -3, -2]]);
+        let r = Rational::from;
+        let mut u = Matrix::from(&[
+            &[r(1), r(-1), r(2)],
+            &[r(3), r(2), r(1)],
+            &[r(2), r(-3), r(-2)],
+        ]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1, 0, 0]));
-        assert_eq!(result.positions[1], Vec::from([0, 1, 0]));
-        assert_eq!(result.positions[2], Vec::from([0, 0, 1]));
+        assert_eq!(result.row(0), Vec::from([r(1), r(0), r(0)]));
+        assert_eq!(result.row(1), Vec::from([r(0), r(1), r(0)]));
+        assert_eq!(result.row(2), Vec::from([r(0), r(0), r(1)]));
 
-        let mut u = Matrix::from(&[&[1, 0, 0], &[0, 1, 0], &[0, 0, 1]]);
+        let mut u = Matrix::from(&[
+            &[r(1), r(0), r(0)],
+            &[r(0), r(1), r(0)],
+            &[r(0), r(0), r(1)],
+        ]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1, 0, 0]));
-        assert_eq!(result.positions[1], Vec::from([0, 1, 0]));
-        assert_eq!(result.positions[2], Vec::from([0, 0, 1]));
+        assert_eq!(result.row(0), Vec::from([r(1), r(0), r(0)]));
+        assert_eq!(result.row(1), Vec::from([r(0), r(1), r(0)]));
+        assert_eq!(result.row(2), Vec::from([r(0), r(0), r(1)]));
 
-        let mut u = Matrix::from(&[&[1, 2], &[3, 4]]);
+        let mut u = Matrix::from(&[&[r(1), r(2)], &[r(3), r(4)]]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1, 0]));
-        assert_eq!(result.positions[1], Vec::from([0, 1]));
+        assert_eq!(result.row(0), Vec::from([r(1), r(0)]));
+        assert_eq!(result.row(1), Vec::from([r(0), r(1)]));
 
-        let mut u = Matrix::from(&[&[1, 2], &[2, 4]]);
+        let mut u = Matrix::from(&[&[r(1), r(2)], &[r(2), r(4)]]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1, 2]));
-        assert_eq!(result.positions[1], Vec::from([0, 0]));
+        assert_eq!(result.row(0), Vec::from([r(1), r(2)]));
+        assert_eq!(result.row(1), Vec::from([r(0), r(0)]));
 
+        // singular: what rounding leaves in the last column is below the
+        // default tolerance, so it no longer reduces to the identity
         let mut u = Matrix::from(&[&[1., 2., 3.], &[4., 5., 6.], &[7., 8., 9.]]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1., 0., 0.]));
-        assert_eq!(result.positions[1], Vec::from([0., 1., 0.]));
-        assert_eq!(result.positions[2], Vec::from([0., 0., 1.]));
+        let expected = [[1., 0., -1.], [0., 1., 2.], [0., 0., 0.]];
+        for (row, expected) in expected.iter().enumerate() {
+            for (value, expected) in result.row(row).iter().zip(expected) {
+                assert!((value - expected).abs() < 1e-12);
+            }
+        }
+        assert_eq!(u.rank(), 2);
+        assert_eq!(u.inverse(), Err(LinalgError::Singular));
 
         let mut u = Matrix::from(&[&[8., 5., -2.], &[4., 7., 20.], &[7., 6., 1.]]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1., 0., 0.]));
-        assert_eq!(result.positions[1], Vec::from([0., 1., 0.]));
-        assert_eq!(result.positions[2], Vec::from([0., 0., 1.]));
+        assert_eq!(result.row(0), Vec::from([1., 0., 0.]));
+        assert_eq!(result.row(1), Vec::from([0., 1., 0.]));
+        assert_eq!(result.row(2), Vec::from([0., 0., 1.]));
     }
 
     #[test]
     fn matrix_rref_edge_cases() {
-        let mut u = Matrix::from(&[&[0, 0], &[0, 0]]);
+        let r = Rational::from;
+        let mut u = Matrix::from(&[&[r(0), r(0)], &[r(0), r(0)]]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([0, 0]));
-        assert_eq!(result.positions[1], Vec::from([0, 0]));
+        assert_eq!(result.row(0), Vec::from([r(0), r(0)]));
+        assert_eq!(result.row(1), Vec::from([r(0), r(0)]));
 
-        let mut u = Matrix::from(&[&[1, 0], &[0, 1]]);
+        let mut u = Matrix::from(&[&[r(1), r(0)], &[r(0), r(1)]]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1, 0]));
-        assert_eq!(result.positions[1], Vec::from([0, 1]));
+        assert_eq!(result.row(0), Vec::from([r(1), r(0)]));
+        assert_eq!(result.row(1), Vec::from([r(0), r(1)]));
 
         let mut u = Matrix::from(&[&[4., 2.], &[2., 1.]]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1., 0.5]));
-        assert_eq!(result.positions[1], Vec::from([0., 0.]));
+        assert_eq!(result.row(0), Vec::from([1., 0.5]));
+        assert_eq!(result.row(1), Vec::from([0., 0.]));
 
-        let mut u = Matrix::from(&[&[-7, 2], &[4, 8]]);
+        let mut u = Matrix::from(&[&[r(-7), r(2)], &[r(4), r(8)]]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1, 0]));
-        assert_eq!(result.positions[1], Vec::from([0, 1]));
+        assert_eq!(result.row(0), Vec::from([r(1), r(0)]));
+        assert_eq!(result.row(1), Vec::from([r(0), r(1)]));
 
-        let mut u = Matrix::from(&[&[1, 2], &[4, 8]]);
+        let mut u = Matrix::from(&[&[r(1), r(2)], &[r(4), r(8)]]);
         let result = u.row_echelon();
-        assert_eq!(result.positions[0], Vec::from([1, 2]));
-        assert_eq!(result.positions[1], Vec::from([0, 0]));
+        assert_eq!(result.row(0), Vec::from([r(1), r(2)]));
+        assert_eq!(result.row(1), Vec::from([r(0), r(0)]));
     }
 
     #[test]
@@ -842,6 +842,77 @@ mod tests {
         assert_eq!(u.determinant(), -16);
     }
 
+    #[test]
+    fn matrix_determinant_n() {
+        let mut u = Matrix::from(&[
+            &[2, -1, 0, 3, 1],
+            &[1, 4, 2, 0, -2],
+            &[0, 3, -1, 2, 5],
+            &[4, 0, 1, -3, 2],
+            &[-1, 2, 3, 1, 0],
+        ]);
+        assert_eq!(u.determinant(), 1556);
+
+        // anti-diagonal: three row swaps
+        let mut u: Matrix<i64> = Matrix::zeros(6, 6);
+        for i in 0..6 {
+            u[(i, 5 - i)] = i as i64 + 1;
+        }
+        assert_eq!(u.determinant(), -720);
+
+        let mut u = Matrix::from(&[
+            &[
+                Rational::new(1, 2),
+                Rational::zero(),
+                Rational::zero(),
+                Rational::zero(),
+                Rational::one(),
+            ],
+            &[
+                Rational::zero(),
+                Rational::new(1, 3),
+                Rational::zero(),
+                Rational::zero(),
+                Rational::zero(),
+            ],
+            &[
+                Rational::zero(),
+                Rational::zero(),
+                Rational::one(),
+                Rational::zero(),
+                Rational::zero(),
+            ],
+            &[
+                Rational::zero(),
+                Rational::zero(),
+                Rational::zero(),
+                Rational::integer(6),
+                Rational::zero(),
+            ],
+            &[
+                Rational::one(),
+                Rational::zero(),
+                Rational::zero(),
+                Rational::zero(),
+                Rational::one(),
+            ],
+        ]);
+        assert_eq!(u.determinant(), Rational::integer(-1));
+
+        // the elimination agrees with the cofactor fast paths
+        let u = Matrix::from(&[
+            &[1, 1, 1, -1],
+            &[1, 1, -1, 1],
+            &[1, -1, 1, 1],
+            &[-1, 1, 1, 1],
+        ]);
+        assert_eq!(u.determinant_bareiss(), -16);
+
+        let mut u: Matrix<f64> = Matrix::identity(7);
+        u[(3, 3)] = 0.;
+        assert_eq!(u.determinant(), 0.);
+    }
+
     #[test]
     fn matrix_determinant_0() {
         let mut u = Matrix::from(&[&[1, 4, 2], &[1, 4, 2], &[3, 9, 5]]);
@@ -871,156 +942,188 @@ mod tests {
 
     #[test]
     fn indentity_matrix_basics() {
-        let mut u = Matrix::from(&[&[1., 2.], &[3., 4.]]);
+        let u = Matrix::from(&[&[1., 2.], &[3., 4.]]);
         let result = u.identity_matrix();
-        assert_eq!(result.positions[0], vec![1., 0.]);
-        assert_eq!(result.positions[1], vec![0., 1.]);
+        assert_eq!(result.row(0), vec![1., 0.]);
+        assert_eq!(result.row(1), vec![0., 1.]);
 
-        let mut u = Matrix::from(&[&[1., 2., 3.], &[4., 5., 6.], &[7., 8., 9.]]);
+        let u = Matrix::from(&[&[1., 2., 3.], &[4., 5., 6.], &[7., 8., 9.]]);
         let result = u.identity_matrix();
-        assert_eq!(result.positions[0], vec![1., 0., 0.]);
-        assert_eq!(result.positions[1], vec![0., 1., 0.]);
-        assert_eq!(result.positions[2], vec![0., 0., 1.]);
+        assert_eq!(result.row(0), vec![1., 0., 0.]);
+        assert_eq!(result.row(1), vec![0., 1., 0.]);
+        assert_eq!(result.row(2), vec![0., 0., 1.]);
 
-        let mut u = Matrix::from(&[
+        let u = Matrix::from(&[
             &[1., 2., 3., 4.],
             &[5., 6., 7., 8.],
             &[8., 9., 10., 11.],
             &[12., 13., 14., 15.],
         ]);
         let result = u.identity_matrix();
-        assert_eq!(result.positions[0], vec![1., 0., 0., 0.]);
-        assert_eq!(result.positions[1], vec![0., 1., 0., 0.]);
-        assert_eq!(result.positions[2], vec![0., 0., 1., 0.]);
-        assert_eq!(result.positions[3], vec![0., 0., 0., 1.]);
+        assert_eq!(result.row(0), vec![1., 0., 0., 0.]);
+        assert_eq!(result.row(1), vec![0., 1., 0., 0.]);
+        assert_eq!(result.row(2), vec![0., 0., 1., 0.]);
+        assert_eq!(result.row(3), vec![0., 0., 0., 1.]);
     }
 
     #[test]
     fn inverse_matrix_2x2() {
-        let mut u = Matrix::from(&[&[1., 0.], &[0., 1.]]);
-        let result = u.inverse();
-        match result {
-            Ok(r) => {
-                assert_eq!(r.positions[0], vec![1., 0.]);
-                assert_eq!(r.positions[1], vec![0., 1.]);
-            }
-            Err(_) => {
-                assert_eq!(0, 1);
-            }
+        let cases = [
+            ([[1., 0.], [0., 1.]], [[1., 0.], [0., 1.]]),
+            ([[2., 0.], [0., 2.]], [[0.5, 0.], [0., 0.5]]),
+            ([[0.5, 0.], [0., 0.5]], [[2., 0.], [0., 2.]]),
+            ([[0., 1.], [1., 0.]], [[0., 1.], [1., 0.]]),
+            ([[1., 2.], [3., 4.]], [[-2., 1.], [1.5, -0.5]]),
+            ([[4., 7.], [2., 6.]], [[0.6, -0.7], [-0.2, 0.4]]),
+        ];
+        for (matrix, expected) in cases {
+            let mut u: Matrix<f32> = Matrix::from(&[&matrix[0], &matrix[1]]);
+            let expected = Matrix::from(&[&expected[0], &expected[1]]);
+            crate::assert_approx_eq!(u.inverse().unwrap(), expected);
         }
+    }
 
-        let mut u = Matrix::from(&[&[2., 0.], &[0., 2.]]);
-        let result = u.inverse();
-        match result {
-            Ok(r) => {
-                assert_eq!(r.positions[0], vec![0.5, 0.]);
-                assert_eq!(r.positions[1], vec![0., 0.5]);
-            }
-            Err(_) => {
-                assert_eq!(0, 1);
-            }
+    #[test]
+    fn inverse_matrix_3x3() {
+        let cases = [
+            (
+                [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+                [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            ),
+            (
+                [[2., 0., 0.], [0., 2., 0.], [0., 0., 2.]],
+                [[0.5, 0., 0.], [0., 0.5, 0.], [0., 0., 0.5]],
+            ),
+            // exactly [[113, 17, -114], [-136, -22, 168], [25, 13, -36]] / 174
+            (
+                [[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]],
+                [
+                    [0.649_425_3, 0.097_701_15, -0.655_172_4],
+                    [-0.781_609_2, -0.126_436_78, 0.965_517_2],
+                    [0.143_678_16, 0.074_712_64, -0.206_896_55],
+                ],
+            ),
+        ];
+        for (matrix, expected) in cases {
+            let mut u: Matrix<f32> = Matrix::from(&[&matrix[0], &matrix[1], &matrix[2]]);
+            let expected = Matrix::from(&[&expected[0], &expected[1], &expected[2]]);
+            crate::assert_approx_eq!(u.inverse().unwrap(), expected);
         }
+    }
 
-        let mut u = Matrix::from(&[&[0.5, 0.], &[0., 0.5]]);
-        let result = u.inverse();
-        match result {
-            Ok(r) => {
-                assert_eq!(r.positions[0], vec![2., 0.]);
-                assert_eq!(r.positions[1], vec![0., 2.]);
-            }
-            Err(_) => {
-                assert_eq!(0, 1);
-            }
-        }
+    #[test]
+    fn inverse_single_elimination() {
+        // tiny but well conditioned: the identity half of [A | I] must not
+        // raise the zero threshold
+        let a = Matrix::from(&[&[1e-20, 0.], &[0., 2e-20]]);
+        crate::assert_approx_eq!(
+            a.inverse_with(Tolerance::default_for(a.shape())).unwrap(),
+            Matrix::from(&[&[1e20, 0.], &[0., 5e19]])
+        );
+        let r = Rational::from;
+        let singular = Matrix::from(&[
+            &[r(1), r(2), r(3)],
+            &[r(2), r(4), r(6)],
+            &[r(1), r(0), r(1)],
+        ]);
+        assert_eq!(
+            singular.inverse_with(Tolerance::exact()),
+            Err(LinalgError::Singular)
+        );
+    }
 
-        let mut u = Matrix::from(&[&[0., 1.], &[1., 0.]]);
-        let result = u.inverse();
-        match result {
-            Ok(r) => {
-                assert_eq!(r.positions[0], vec![0., 1.]);
-                assert_eq!(r.positions[1], vec![1., 0.]);
-            }
-            Err(_) => {
-                assert_eq!(0, 1);
-            }
-        }
+    #[test]
+    fn matrix_complex() {
+        let i = Complex::<f64>::i();
+        let one = Complex::from(1.);
+        let zero = Complex::from(0.);
+
+        let u = Matrix::from(&[&[one, i], &[Complex::new(2., -1.), zero]]);
+        let result = u.conjugate_transpose();
+        assert_eq!(result.row(0), vec![one, Complex::new(2., 1.)]);
+        assert_eq!(result.row(1), vec![-i, zero]);
+
+        let mut u = Matrix::from(&[&[one, i], &[i, one]]);
+        assert_eq!(u.determinant(), Complex::from(2.));
+        assert_eq!(u.rank(), 2);
 
-        let mut u = Matrix::from(&[&[1., 2.], &[3., 4.]]);
-        let result = u.inverse();
-        match result {
-            Ok(r) => {
-                assert_eq!(r.positions[0], vec![-2., 1.]);
-                assert_eq!(r.positions[1], vec![1.5, -0.5]);
-            }
-            Err(_) => {
-                assert_eq!(0, 1);
-            }
-        }
+        let mut u = Matrix::from(&[&[one, i], &[i, -one]]);
+        assert_eq!(u.determinant(), zero);
+        assert_eq!(u.rank(), 1);
+        assert!(u.inverse().is_err());
 
-        let mut u = Matrix::from(&[&[4., 7.], &[2., 6.]]);
-        let result = u.inverse();
-        match result {
-            Ok(r) => {
-                assert_eq!(r.positions[0], vec![0.6, -0.7]);
-                assert_eq!(r.positions[1], vec![-0.2, 0.4]);
-            }
-            Err(_) => {
-                assert_eq!(0, 1);
-            }
-        }
+        let mut u = Matrix::from(&[&[one, i], &[zero, Complex::from(2.)]]);
+        let result = u.inverse().unwrap();
+        assert_eq!(result.row(0), vec![one, Complex::new(0., -0.5)]);
+        assert_eq!(result.row(1), vec![zero, Complex::from(0.5)]);
+
+        let mut u = Matrix::from(&[
+            &[one, zero, zero],
+            &[zero, i, zero],
+            &[zero, zero, Complex::from(2.)],
+        ]);
+        let result = u.inverse().unwrap();
+        assert_eq!(result.row(0), vec![one, zero, zero]);
+        assert_eq!(result.row(1), vec![zero, -i, zero]);
+        assert_eq!(result.row(2), vec![zero, zero, Complex::from(0.5)]);
+
+        let mut u = Matrix::from(&[&[i, Complex::from(2.)], &[one, Complex::new(0., -2.)]]);
+        let result = u.row_echelon();
+        assert_eq!(result.row(0), vec![one, Complex::new(0., -2.)]);
+        assert_eq!(result.row(1), vec![zero, zero]);
     }
 
     #[test]
-    fn inverse_matrix_3x3() {
-        let mut u = Matrix::from(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., 1.]]);
-        let result = u.inverse();
-        match result {
-            Ok(r) => {
-                assert_eq!(r.positions[0], vec![1., 0., 0.]);
-                assert_eq!(r.positions[1], vec![0., 1., 0.]);
-                assert_eq!(r.positions[2], vec![0., 0., 1.]);
-            }
-            Err(_) => {
-                assert_eq!(0, 1);
-            }
-        }
+    fn matrix_rational() {
+        let r = |n: i64, d: i64| Rational::new(n, d);
+        let rows: Vec<Vec<Rational>> = [[8, 5, -2], [4, 7, 20], [7, 6, 1]]
+            .iter()
+            .map(|row| row.iter().map(|&x| Rational::from(x)).collect())
+            .collect();
+        let rows: Vec<&[Rational]> = rows.iter().map(|row| row.as_slice()).collect();
+
+        let mut u = Matrix::from(&rows);
+        assert_eq!(u.determinant(), Rational::from(-174));
+        assert_eq!(u.rank(), 3);
+        let result = u.inverse().unwrap();
+        assert_eq!(result.row(0), vec![r(113, 174), r(17, 174), r(-19, 29)]);
+        assert_eq!(result.row(1), vec![r(-68, 87), r(-11, 87), r(28, 29)]);
+        assert_eq!(result.row(2), vec![r(25, 174), r(13, 174), r(-6, 29)]);
+        assert_eq!(
+            result.to_string(),
+            "[\n[113/174,17/174,-19/29]\n[-68/87,-11/87,28/29]\n[25/174,13/174,-6/29]\n]"
+        );
 
-        let mut u = Matrix::from(&[&[2., 0., 0.], &[0., 2., 0.], &[0., 0., 2.]]);
-        let result = u.inverse();
-        match result {
-            Ok(r) => {
-                assert_eq!(r.positions[0], vec![0.5, 0., 0.]);
-                assert_eq!(r.positions[1], vec![0., 0.5, 0.]);
-                assert_eq!(r.positions[2], vec![0., 0., 0.5]);
-            }
-            Err(_) => {
-                assert_eq!(0, 1);
-            }
-        }
+        let mut u = Matrix::from(&[
+            &[r(1, 1), r(2, 1), r(3, 1)],
+            &[r(4, 1), r(5, 1), r(6, 1)],
+            &[r(7, 1), r(8, 1), r(9, 1)],
+        ]);
+        let result = u.row_echelon();
+        assert_eq!(result.row(0), vec![r(1, 1), r(0, 1), r(-1, 1)]);
+        assert_eq!(result.row(1), vec![r(0, 1), r(1, 1), r(2, 1)]);
+        assert_eq!(result.row(2), vec![r(0, 1), r(0, 1), r(0, 1)]);
+        assert_eq!(u.rank(), 2);
+        assert_eq!(u.determinant(), r(0, 1));
+        assert!(u.inverse().is_err());
 
-        let mut u = Matrix::from(&[&[8., 5., -2.], &[4., 7., 20.], &[7., 6., 1.]]);
-        let result = u.inverse();
-        match result {
-            Ok(r) => {
-                assert_eq!(r.positions[0], vec![0.649425287, 0.097701149, -0.655172414]);
-                assert_eq!(
-                    r.positions[1],
-                    vec![-0.781609195, -0.126436782, 0.965517241]
-                );
-                assert_eq!(r.positions[2], vec![0.143678161, 0.07471265, -0.206896552]);
-            }
-            Err(_) => {
-                assert_eq!(0, 1);
-            }
-        }
+        let mut u = Matrix::from(&[&[r(1, 2), r(1, 3)], &[r(1, 4), r(1, 5)]]);
+        let result = u.inverse().unwrap();
+        assert_eq!(result.row(0), vec![r(12, 1), r(-20, 1)]);
+        assert_eq!(result.row(1), vec![r(-15, 1), r(30, 1)]);
     }
 
     #[test]
     fn matrix_rank() {
+        let r = Rational::from;
         let mut u = Matrix::from(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., 1.]]);
         assert_eq!(u.rank(), 3);
 
-        let mut u = Matrix::from(&[&[1, 2, 0, 0], &[2, 4, 0, 0], &[-1, 2, 1, 1]]);
+        let mut u = Matrix::from(&[
+            &[r(1), r(2), r(0), r(0)],
+            &[r(2), r(4), r(0), r(0)],
+            &[r(-1), r(2), r(1), r(1)],
+        ]);
         assert_eq!(u.rank(), 2);
 
         let mut u = Matrix::from(&[
@@ -1031,31 +1134,149 @@ mod tests {
         ]);
         assert_eq!(u.rank(), 3);
 
-        let mut u = Matrix::from(&[&[0, 0], &[0, 0]]);
+        let mut u = Matrix::from(&[&[r(0), r(0)], &[r(0), r(0)]]);
         assert_eq!(u.rank(), 0);
 
-        let mut u = Matrix::from(&[&[1, 0], &[0, 1]]);
+        let mut u = Matrix::from(&[&[r(1), r(0)], &[r(0), r(1)]]);
         assert_eq!(u.rank(), 2);
 
-        let mut u = Matrix::from(&[&[2, 0], &[0, 2]]);
+        let mut u = Matrix::from(&[&[r(2), r(0)], &[r(0), r(2)]]);
         assert_eq!(u.rank(), 2);
 
-        let mut u = Matrix::from(&[&[1, 1], &[1, 1]]);
+        let mut u = Matrix::from(&[&[r(1), r(1)], &[r(1), r(1)]]);
         assert_eq!(u.rank(), 1);
 
-        let mut u = Matrix::from(&[&[0, 1], &[1, 0]]);
+        let mut u = Matrix::from(&[&[r(0), r(1)], &[r(1), r(0)]]);
         assert_eq!(u.rank(), 2);
 
-        let mut u = Matrix::from(&[&[1, 2], &[3, 4]]);
+        let mut u = Matrix::from(&[&[r(1), r(2)], &[r(3), r(4)]]);
         assert_eq!(u.rank(), 2);
 
-        let mut u = Matrix::from(&[&[-7, 5], &[4, 6]]);
+        let mut u = Matrix::from(&[&[r(-7), r(5)], &[r(4), r(6)]]);
         assert_eq!(u.rank(), 2);
 
-        let mut u = Matrix::from(&[&[1, 0, 0], &[0, 1, 0], &[0, 0, 1]]);
+        let mut u = Matrix::from(&[
+            &[r(1), r(0), r(0)],
+            &[r(0), r(1), r(0)],
+            &[r(0), r(0), r(1)],
+        ]);
         assert_eq!(u.rank(), 3);
 
-        let mut u = Matrix::from(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]);
+        let mut u = Matrix::from(&[
+            &[r(1), r(2), r(3)],
+            &[r(4), r(5), r(6)],
+            &[r(7), r(8), r(9)],
+        ]);
         assert_eq!(u.rank(), 2);
     }
+
+    #[test]
+    fn matrix_errors() {
+        let mut u = Matrix::from(&[&[1, 2], &[3, 4]]);
+        let v = Matrix::from(&[&[1, 2, 3], &[4, 5, 6]]);
+        let mismatch = LinalgError::ShapeMismatch {
+            left: (2, 2),
+            right: (2, 3),
+        };
+        assert_eq!(u.try_add(&v), Err(mismatch.clone()));
+        assert_eq!(u.try_sub(&v), Err(mismatch));
+        assert_eq!(u.row(0), vec![1, 2]);
+
+        assert_eq!(
+            v.try_mul_mat(&u).map(|m| m.to_rows()),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 3),
+                right: (2, 2)
+            })
+        );
+        assert_eq!(
+            u.try_mul_mat(&v).map(|m| m.to_rows()),
+            Ok(vec![vec![9, 12, 15], vec![19, 26, 33]])
+        );
+        assert_eq!(
+            v.try_mul_vec(&Vector::from(&[1, 1])).map(|v| v.positions),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 3),
+                right: (2, 1)
+            })
+        );
+
+        let mut w = v.clone();
+        assert_eq!(
+            w.try_determinant(),
+            Err(LinalgError::NotSquare { shape: (2, 3) })
+        );
+        let mut w = Matrix::from(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        assert_eq!(
+            w.inverse().map(|m| m.to_rows()),
+            Err(LinalgError::NotSquare { shape: (2, 3) })
+        );
+        let mut w = Matrix::from(&[&[1., 2.], &[2., 4.]]);
+        assert_eq!(w.inverse().map(|m| m.to_rows()), Err(LinalgError::Singular));
+        assert_eq!(LinalgError::Singular.to_string(), "matrix is singular");
+    }
+
+    #[test]
+    fn matrix_construction() {
+        let u = Matrix::from(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(u.shape(), (2, 3));
+        assert_eq!(u.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(u[(1, 0)], 4);
+        assert_eq!(u.get(1, 2), Some(&6));
+        assert_eq!(u.get(2, 0), None);
+        assert_eq!(u.column(1).positions, vec![2, 5]);
+        assert_eq!(Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]), Ok(u));
+        assert_eq!(
+            Matrix::from_vec(2, 2, vec![1, 2, 3]),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 2),
+                right: (3, 1)
+            })
+        );
+        assert_eq!(
+            Matrix::try_from_rows(&[&[1, 2], &[3]]),
+            Err(LinalgError::RaggedRows {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Matrix::<f64>::identity(2).to_rows(),
+            vec![vec![1., 0.], vec![0., 1.]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ragged rows")]
+    fn matrix_from_ragged_panics() {
+        Matrix::from(&[&[1, 2], &[3]]);
+    }
+
+    #[test]
+    fn matrix_empty_shapes() {
+        let mut empty: Matrix<f64> = Matrix::from(&[]);
+        assert_eq!(empty.shape(), (0, 0));
+        assert!(empty.is_square());
+        assert_eq!(empty.trace(), 0.);
+        assert_eq!(empty.determinant(), 1.);
+        assert_eq!(empty.rank(), 0);
+        assert_eq!(empty.inverse(), Ok(Matrix::zeros(0, 0)));
+
+        let mut wide: Matrix<f64> = Matrix::zeros(0, 3);
+        assert_eq!(wide.shape(), (0, 3));
+        assert!(!wide.is_square());
+        assert_eq!(wide.transpose().shape(), (3, 0));
+        assert_eq!(wide.rank(), 0);
+
+        let mut tall: Matrix<f64> = Matrix::from(&[&[], &[]]);
+        assert_eq!(tall.shape(), (2, 0));
+        assert_eq!(tall.to_string(), "[\n[]\n[]\n]");
+        assert_eq!(tall.try_mul_mat(&wide), Ok(Matrix::zeros(2, 3)));
+        assert_eq!(tall.mul_vec(Vector::from(&[])).positions, vec![0., 0.]);
+        assert_eq!(
+            tall.try_determinant(),
+            Err(LinalgError::NotSquare { shape: (2, 0) })
+        );
+    }
 }
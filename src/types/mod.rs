@@ -1,5 +1,31 @@
+mod complex;
+pub use complex::Complex;
+
+mod echelon;
+pub use echelon::Echelon;
+
 mod matrix;
 pub use matrix::Matrix;
 
+mod ops;
+
+mod rational;
+pub use rational::Rational;
+
+mod row_ops;
+pub use row_ops::{RowOp, RowOpLog, RowOpStep};
+
+mod scalar;
+pub use scalar::{Field, Float, Real, Scalar};
+
+mod smatrix;
+pub use smatrix::SMatrix;
+
+mod svector;
+pub use svector::SVector;
+
+mod tolerance;
+pub use tolerance::Tolerance;
+
 mod vector;
 pub use vector::Vector;
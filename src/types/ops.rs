@@ -0,0 +1,324 @@
+//! `std::ops` impls for `Vector` and `Matrix`.
+//!
+//! Every binary operator accepts owned and borrowed operands on both sides.
+//! Shapes are checked at runtime and a mismatch panics with the same message
+//! as the corresponding named method; use the `try_*` methods to get a
+//! `LinalgError` instead.
+
+use crate::types::{Complex, Matrix, Rational, Real, Scalar, Vector};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// `+`, `-` and their assign forms, built on the in-place `add`/`sub`.
+macro_rules! impl_add_sub {
+    ($Type:ident) => {
+        impl<K: Scalar> AddAssign<&$Type<K>> for $Type<K> {
+            fn add_assign(&mut self, other: &$Type<K>) {
+                $Type::add(self, other);
+            }
+        }
+
+        impl<K: Scalar> AddAssign<$Type<K>> for $Type<K> {
+            fn add_assign(&mut self, other: $Type<K>) {
+                $Type::add(self, &other);
+            }
+        }
+
+        impl<K: Scalar> SubAssign<&$Type<K>> for $Type<K> {
+            fn sub_assign(&mut self, other: &$Type<K>) {
+                $Type::sub(self, other);
+            }
+        }
+
+        impl<K: Scalar> SubAssign<$Type<K>> for $Type<K> {
+            fn sub_assign(&mut self, other: $Type<K>) {
+                $Type::sub(self, &other);
+            }
+        }
+
+        impl<K: Scalar> Add<&$Type<K>> for $Type<K> {
+            type Output = $Type<K>;
+
+            fn add(mut self, other: &$Type<K>) -> $Type<K> {
+                self += other;
+                self
+            }
+        }
+
+        impl<K: Scalar> Add<$Type<K>> for $Type<K> {
+            type Output = $Type<K>;
+
+            fn add(self, other: $Type<K>) -> $Type<K> {
+                self + &other
+            }
+        }
+
+        impl<K: Scalar> Add<&$Type<K>> for &$Type<K> {
+            type Output = $Type<K>;
+
+            fn add(self, other: &$Type<K>) -> $Type<K> {
+                self.clone() + other
+            }
+        }
+
+        impl<K: Scalar> Add<$Type<K>> for &$Type<K> {
+            type Output = $Type<K>;
+
+            fn add(self, other: $Type<K>) -> $Type<K> {
+                self.clone() + &other
+            }
+        }
+
+        impl<K: Scalar> Sub<&$Type<K>> for $Type<K> {
+            type Output = $Type<K>;
+
+            fn sub(mut self, other: &$Type<K>) -> $Type<K> {
+                self -= other;
+                self
+            }
+        }
+
+        impl<K: Scalar> Sub<$Type<K>> for $Type<K> {
+            type Output = $Type<K>;
+
+            fn sub(self, other: $Type<K>) -> $Type<K> {
+                self - &other
+            }
+        }
+
+        impl<K: Scalar> Sub<&$Type<K>> for &$Type<K> {
+            type Output = $Type<K>;
+
+            fn sub(self, other: &$Type<K>) -> $Type<K> {
+                self.clone() - other
+            }
+        }
+
+        impl<K: Scalar> Sub<$Type<K>> for &$Type<K> {
+            type Output = $Type<K>;
+
+            fn sub(self, other: $Type<K>) -> $Type<K> {
+                self.clone() - &other
+            }
+        }
+    };
+}
+
+/// Unary minus and multiplication by a `K` on the right.
+macro_rules! impl_neg_scale {
+    ($Type:ident) => {
+        impl<K: Scalar> MulAssign<K> for $Type<K> {
+            fn mul_assign(&mut self, scalar: K) {
+                $Type::scl(self, scalar);
+            }
+        }
+
+        impl<K: Scalar> Mul<K> for $Type<K> {
+            type Output = $Type<K>;
+
+            fn mul(mut self, scalar: K) -> $Type<K> {
+                self *= scalar;
+                self
+            }
+        }
+
+        impl<K: Scalar> Mul<K> for &$Type<K> {
+            type Output = $Type<K>;
+
+            fn mul(self, scalar: K) -> $Type<K> {
+                self.clone() * scalar
+            }
+        }
+
+        impl<K: Scalar> Neg for $Type<K> {
+            type Output = $Type<K>;
+
+            fn neg(self) -> $Type<K> {
+                self * -K::one()
+            }
+        }
+
+        impl<K: Scalar> Neg for &$Type<K> {
+            type Output = $Type<K>;
+
+            fn neg(self) -> $Type<K> {
+                self.clone() * -K::one()
+            }
+        }
+    };
+}
+
+impl_add_sub!(Vector);
+impl_add_sub!(Matrix);
+impl_neg_scale!(Vector);
+impl_neg_scale!(Matrix);
+
+/// Matrix products for the four owned/borrowed combinations, all going
+/// through the `&Matrix * &$Rhs` impl.
+macro_rules! impl_matrix_product {
+    ($Rhs:ident, $try_mul:ident, $message:literal) => {
+        impl<K: Scalar> Mul<&$Rhs<K>> for &Matrix<K> {
+            type Output = $Rhs<K>;
+
+            fn mul(self, other: &$Rhs<K>) -> $Rhs<K> {
+                self.$try_mul(other)
+                    .unwrap_or_else(|e| panic!("{}: {e}", $message))
+            }
+        }
+
+        impl<K: Scalar> Mul<$Rhs<K>> for &Matrix<K> {
+            type Output = $Rhs<K>;
+
+            fn mul(self, other: $Rhs<K>) -> $Rhs<K> {
+                self * &other
+            }
+        }
+
+        impl<K: Scalar> Mul<&$Rhs<K>> for Matrix<K> {
+            type Output = $Rhs<K>;
+
+            fn mul(self, other: &$Rhs<K>) -> $Rhs<K> {
+                &self * other
+            }
+        }
+
+        impl<K: Scalar> Mul<$Rhs<K>> for Matrix<K> {
+            type Output = $Rhs<K>;
+
+            fn mul(self, other: $Rhs<K>) -> $Rhs<K> {
+                &self * &other
+            }
+        }
+    };
+}
+
+impl_matrix_product!(Matrix, try_mul_mat, "Can't multiply Matrix");
+impl_matrix_product!(Vector, try_mul_vec, "Can't multiply Matrix by Vector");
+
+impl<K: Scalar> MulAssign<&Matrix<K>> for Matrix<K> {
+    fn mul_assign(&mut self, other: &Matrix<K>) {
+        *self = &*self * other;
+    }
+}
+
+impl<K: Scalar> MulAssign<Matrix<K>> for Matrix<K> {
+    fn mul_assign(&mut self, other: Matrix<K>) {
+        *self = &*self * &other;
+    }
+}
+
+/// `scalar * v` for a concrete scalar type; coherence rules out a blanket
+/// impl over `K`, so each scalar type gets its own.
+macro_rules! impl_scalar_left {
+    ($($generics:ident)? ; $K:ty) => {
+        impl$(<$generics: Real>)? Mul<Vector<$K>> for $K {
+            type Output = Vector<$K>;
+
+            fn mul(self, vector: Vector<$K>) -> Vector<$K> {
+                vector * self
+            }
+        }
+
+        impl$(<$generics: Real>)? Mul<&Vector<$K>> for $K {
+            type Output = Vector<$K>;
+
+            fn mul(self, vector: &Vector<$K>) -> Vector<$K> {
+                vector * self
+            }
+        }
+
+        impl$(<$generics: Real>)? Mul<Matrix<$K>> for $K {
+            type Output = Matrix<$K>;
+
+            fn mul(self, matrix: Matrix<$K>) -> Matrix<$K> {
+                matrix * self
+            }
+        }
+
+        impl$(<$generics: Real>)? Mul<&Matrix<$K>> for $K {
+            type Output = Matrix<$K>;
+
+            fn mul(self, matrix: &Matrix<$K>) -> Matrix<$K> {
+                matrix * self
+            }
+        }
+    };
+}
+
+impl_scalar_left!(; f32);
+impl_scalar_left!(; f64);
+impl_scalar_left!(; i8);
+impl_scalar_left!(; i16);
+impl_scalar_left!(; i32);
+impl_scalar_left!(; i64);
+impl_scalar_left!(; i128);
+impl_scalar_left!(; isize);
+impl_scalar_left!(; Rational);
+impl_scalar_left!(T; Complex<T>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_operators() {
+        let u = Vector::from(&[2., 3.]);
+        let v = Vector::from(&[5., 7.]);
+        assert_eq!((&u + &v).positions, [7., 10.]);
+        assert_eq!((&u - &v).positions, [-3., -4.]);
+        assert_eq!((u.clone() + &v).positions, [7., 10.]);
+        assert_eq!((&u - v.clone()).positions, [-3., -4.]);
+        assert_eq!((-&u).positions, [-2., -3.]);
+        assert_eq!((&u * 2.).positions, [4., 6.]);
+        assert_eq!((2_f64 * &u).positions, [4., 6.]);
+
+        let mut w = u.clone();
+        w += &v;
+        w -= u;
+        w *= 3.;
+        assert_eq!(w.positions, [15., 21.]);
+        assert_eq!((-w).positions, [-15., -21.]);
+
+        let u: Vector<i32> = Vector::from(&[1, -2]);
+        assert_eq!((3 * u * 2).positions, [6, -12]);
+    }
+
+    #[test]
+    fn matrix_operators() {
+        let a = Matrix::from(&[&[1, 2], &[3, 4]]);
+        let b = Matrix::from(&[&[0, 1], &[1, 0]]);
+        assert_eq!(&a + &b, Matrix::from(&[&[1, 3], &[4, 4]]));
+        assert_eq!(&a - &b, Matrix::from(&[&[1, 1], &[2, 4]]));
+        assert_eq!(&a * &b, Matrix::from(&[&[2, 1], &[4, 3]]));
+        assert_eq!(&b * &a, Matrix::from(&[&[3, 4], &[1, 2]]));
+        assert_eq!(-&a, Matrix::from(&[&[-1, -2], &[-3, -4]]));
+        assert_eq!(&a * 3, 3 * &a);
+        assert_eq!((&a * Vector::from(&[1, 1])).positions, [3, 7]);
+
+        let mut c = a.clone();
+        c *= &b;
+        c += &a;
+        c -= Matrix::identity(2);
+        assert_eq!(c, Matrix::from(&[&[2, 3], &[7, 6]]));
+
+        let r = Matrix::from(&[&[Rational::new(1, 2)]]);
+        assert_eq!(
+            Rational::integer(4) * r,
+            Matrix::from(&[&[Rational::integer(2)]])
+        );
+        let z = Vector::from(&[Complex::new(1., 1.)]);
+        assert_eq!((Complex::i() * z).positions, [Complex::new(-1., 1.)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't multiply Matrix: shape mismatch")]
+    fn matrix_product_shape_panics() {
+        let a = Matrix::from(&[&[1, 2, 3]]);
+        let _ = &a * &a;
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't add Vectors")]
+    fn vector_add_shape_panics() {
+        let _ = Vector::from(&[1, 2]) + Vector::from(&[1]);
+    }
+}
@@ -0,0 +1,419 @@
+use crate::types::{Field, Real, Scalar};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// An exact fraction `numerator / denominator` of two `i64`.
+///
+/// Values are always kept normalized: the denominator is positive and shares
+/// no factor with the numerator, so equal values have equal representations.
+/// Intermediate results are computed on `i128`; an operator whose reduced
+/// result no longer fits in `i64` panics, the `checked_*` methods return
+/// `None` instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    a = a.abs();
+    b = b.abs();
+    while b != 0 {
+        let tmp = a % b;
+        a = b;
+        b = tmp;
+    }
+    a
+}
+
+impl Rational {
+    /// `numerator / denominator` in lowest terms. Panics if `denominator`
+    /// is zero, or if normalizing overflows, as for `i64::MIN / -1`.
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        if denominator == 0 {
+            panic!("Rational denominator must be non zero");
+        }
+        Rational::checked_new(numerator as i128, denominator as i128)
+            .expect("Rational overflow in construction")
+    }
+
+    pub fn integer(value: i64) -> Self {
+        Rational {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    fn checked_new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator);
+        let sign = if denominator < 0 { -1 } else { 1 };
+        let numerator = i64::try_from(sign * numerator / divisor).ok()?;
+        let denominator = i64::try_from(sign * denominator / divisor).ok()?;
+        Some(Rational {
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        let numerator = self.numerator as i128 * other.denominator as i128
+            + other.numerator as i128 * self.denominator as i128;
+        let denominator = self.denominator as i128 * other.denominator as i128;
+        Rational::checked_new(numerator, denominator)
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+        self.checked_add(-other)
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        let numerator = self.numerator as i128 * other.numerator as i128;
+        let denominator = self.denominator as i128 * other.denominator as i128;
+        Rational::checked_new(numerator, denominator)
+    }
+
+    /// `None` on overflow or division by zero.
+    pub fn checked_div(self, other: Rational) -> Option<Rational> {
+        let numerator = self.numerator as i128 * other.denominator as i128;
+        let denominator = self.denominator as i128 * other.numerator as i128;
+        Rational::checked_new(numerator, denominator)
+    }
+
+    /// Closest fraction to `value` whose denominator does not exceed
+    /// `max_denominator`: the last continued-fraction convergent within the
+    /// bound, or the semiconvergent after it when that is closer.
+    /// Infinities saturate to `±i64::MAX`.
+    ///
+    /// Panics if `value` is NaN, which has no rational approximation, or if
+    /// `max_denominator` is less than one.
+    pub fn approximate(value: f64, max_denominator: i64) -> Self {
+        assert!(!value.is_nan(), "can't approximate NaN by a rational");
+        assert!(
+            max_denominator >= 1,
+            "Rational denominator bound must be positive, got {max_denominator}"
+        );
+        let sign = if value < 0. { -1 } else { 1 };
+        if value.is_infinite() {
+            return Rational::integer(sign * i64::MAX);
+        }
+        let mut rest = value.abs();
+        let (mut p0, mut q0, mut p1, mut q1): (i128, i128, i128, i128) = (0, 1, 1, 0);
+        loop {
+            let whole = rest.floor();
+            if whole > i64::MAX as f64 {
+                break;
+            }
+            let a = whole as i128;
+            let p2 = a * p1 + p0;
+            let q2 = a * q1 + q0;
+            if q2 > max_denominator as i128 || p2 > i64::MAX as i128 {
+                // (p0 + k·p1)/(q0 + k·q1) for the largest k still in bounds
+                // may beat p1/q1
+                if q1 > 0 {
+                    let mut k = (max_denominator as i128 - q0) / q1;
+                    if p1 > 0 {
+                        k = k.min((i64::MAX as i128 - p0) / p1);
+                    }
+                    let error = |p: i128, q: i128| (value.abs() - p as f64 / q as f64).abs();
+                    let (p, q) = (p0 + k * p1, q0 + k * q1);
+                    if k > 0 && error(p, q) < error(p1, q1) {
+                        (p1, q1) = (p, q);
+                    }
+                }
+                break;
+            }
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+            let fraction = rest - whole;
+            if fraction < f64::EPSILON {
+                break;
+            }
+            rest = 1. / fraction;
+        }
+        if q1 == 0 {
+            return Rational::integer(sign * i64::MAX);
+        }
+        Rational::new(sign * p1 as i64, q1 as i64)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::integer(value)
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Self {
+        Rational::integer(value as i64)
+    }
+}
+
+impl std::ops::Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        self.checked_add(other)
+            .expect("Rational overflow in addition")
+    }
+}
+
+impl std::ops::Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self.checked_sub(other)
+            .expect("Rational overflow in subtraction")
+    }
+}
+
+impl std::ops::Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        self.checked_mul(other)
+            .expect("Rational overflow in multiplication")
+    }
+}
+
+impl std::ops::Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        if other.numerator == 0 {
+            panic!("Rational division by zero");
+        }
+        self.checked_div(other)
+            .expect("Rational overflow in division")
+    }
+}
+
+impl std::ops::Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: self
+                .numerator
+                .checked_neg()
+                .expect("Rational overflow in negation"),
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        let left = self.numerator as i128 * other.denominator as i128;
+        let right = other.numerator as i128 * self.denominator as i128;
+        left.cmp(&right)
+    }
+}
+
+impl Scalar for Rational {
+    type Real = Rational;
+
+    fn zero() -> Self {
+        Rational::integer(0)
+    }
+
+    fn one() -> Self {
+        Rational::integer(1)
+    }
+
+    fn abs(self) -> Self {
+        if self.numerator < 0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Exact when both terms are perfect squares, otherwise the closest
+    /// fraction with a denominator up to one million.
+    ///
+    /// Panics on a negative value, whose square root is not real.
+    fn sqrt(self) -> Self {
+        assert!(
+            self.numerator >= 0,
+            "square root of the negative rational {self}"
+        );
+        let numerator = self.numerator.isqrt();
+        let denominator = self.denominator.isqrt();
+        if numerator * numerator == self.numerator && denominator * denominator == self.denominator
+        {
+            return Rational::new(numerator, denominator);
+        }
+        Rational::approximate(self.to_f64().sqrt(), 1_000_000)
+    }
+
+    fn epsilon() -> Self {
+        Rational::zero()
+    }
+
+    fn re(self) -> Self {
+        self
+    }
+
+    fn from_real(value: Self) -> Self {
+        value
+    }
+}
+
+impl Field for Rational {}
+
+impl Real for Rational {
+    fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    fn from_usize(value: usize) -> Self {
+        let value =
+            i64::try_from(value).unwrap_or_else(|_| panic!("{value} does not fit in a Rational"));
+        Rational::integer(value)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rational_normalization() {
+        let r = Rational::new(2, -4);
+        assert_eq!(r.numerator(), -1);
+        assert_eq!(r.denominator(), 2);
+        assert_eq!(Rational::new(0, -7), Rational::zero());
+        assert_eq!(Rational::new(6, 3), Rational::integer(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational denominator must be non zero")]
+    fn rational_zero_denominator_panics() {
+        Rational::new(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational overflow in construction")]
+    fn rational_construction_overflow_panics() {
+        Rational::new(i64::MIN, -1);
+    }
+
+    #[test]
+    fn rational_arithmetic() {
+        let a = Rational::new(1, 3);
+        let b = Rational::new(1, 6);
+        assert_eq!(a + b, Rational::new(1, 2));
+        assert_eq!(a - b, Rational::new(1, 6));
+        assert_eq!(a * b, Rational::new(1, 18));
+        assert_eq!(a / b, Rational::integer(2));
+        assert_eq!(-a, Rational::new(-1, 3));
+        assert_eq!(Scalar::recip(Rational::new(-2, 5)), Rational::new(-5, 2));
+        assert!(Rational::new(1, 3) > Rational::new(1, 4));
+        assert!(Rational::new(-1, 3) < Rational::new(-1, 4));
+    }
+
+    #[test]
+    fn rational_overflow_is_detected() {
+        let big = Rational::integer(i64::MAX);
+        assert_eq!(big.checked_add(Rational::one()), None);
+        assert_eq!(big.checked_mul(Rational::integer(2)), None);
+        assert_eq!(Rational::one().checked_div(Rational::zero()), None);
+        // reduced results that fit are fine even if the cross products don't
+        let a = Rational::new(i64::MAX, 3);
+        assert_eq!(a * Rational::new(3, i64::MAX), Rational::one());
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational overflow in addition")]
+    fn rational_overflow_panics() {
+        let _ = Rational::integer(i64::MAX) + Rational::one();
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational division by zero")]
+    fn rational_division_by_zero_panics() {
+        let _ = Rational::one() / Rational::zero();
+    }
+
+    #[test]
+    fn rational_sqrt() {
+        assert_eq!(Scalar::sqrt(Rational::new(9, 4)), Rational::new(3, 2));
+        let root_two = Scalar::sqrt(Rational::integer(2)).to_f64();
+        assert!((root_two - 2_f64.sqrt()).abs() < 1e-11);
+        assert_eq!(
+            Rational::approximate(f64::NEG_INFINITY, 10),
+            Rational::integer(-i64::MAX)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "square root of the negative rational -1")]
+    fn rational_sqrt_of_negative_panics() {
+        Scalar::sqrt(Rational::integer(-1));
+    }
+
+    #[test]
+    fn rational_approximate() {
+        let pi = std::f64::consts::PI;
+        assert_eq!(Rational::approximate(pi, 7), Rational::new(22, 7));
+        // the semiconvergent 311/99 beats the convergent 22/7
+        assert_eq!(Rational::approximate(pi, 100), Rational::new(311, 99));
+        assert_eq!(Rational::approximate(pi, 106), Rational::new(333, 106));
+        assert_eq!(Rational::approximate(-0.75, 1), Rational::integer(-1));
+        assert_eq!(Rational::approximate(0.3, 1000), Rational::new(3, 10));
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator bound must be positive, got 0")]
+    fn rational_approximate_zero_bound_panics() {
+        Rational::approximate(0.5, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't approximate NaN")]
+    fn rational_approximate_nan_panics() {
+        Rational::approximate(f64::NAN, 10);
+    }
+
+    #[test]
+    fn rational_display() {
+        assert_eq!(Rational::new(1, 3).to_string(), "1/3");
+        assert_eq!(Rational::new(-4, 6).to_string(), "-2/3");
+        assert_eq!(Rational::new(8, 4).to_string(), "2");
+    }
+}
@@ -0,0 +1,263 @@
+use crate::types::{Echelon, Field, Matrix, Tolerance};
+use crate::LinalgError;
+use std::fmt;
+
+/// An elementary row operation. Rows are indexed from zero but displayed
+/// from one, as in `R1 ↔ R3`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowOp<K> {
+    /// `Ra ↔ Rb`
+    Swap(usize, usize),
+    /// `Rrow ← Rrow / divisor`
+    Divide { row: usize, divisor: K },
+    /// `Rtarget ← Rtarget − factor·Rsource`
+    Subtract {
+        target: usize,
+        source: usize,
+        factor: K,
+    },
+}
+
+impl<K: Field> RowOp<K> {
+    /// Panics if a row index is out of bounds for `matrix`.
+    pub fn apply(&self, matrix: &mut Matrix<K>) {
+        match *self {
+            RowOp::Swap(a, b) => matrix.swap_rows(a, b),
+            RowOp::Divide { row, divisor } => {
+                for point in matrix.row_mut(row) {
+                    *point = *point / divisor;
+                }
+            }
+            RowOp::Subtract {
+                target,
+                source,
+                factor,
+            } => {
+                for i in 0..matrix.shape().1 {
+                    matrix[(target, i)] = matrix[(target, i)] - matrix[(source, i)] * factor;
+                }
+            }
+        }
+    }
+}
+
+impl<K: fmt::Display> fmt::Display for RowOp<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowOp::Swap(a, b) => write!(f, "R{} ↔ R{}", a + 1, b + 1),
+            RowOp::Divide { row, divisor } => {
+                write!(f, "R{} ← R{} / {divisor}", row + 1, row + 1)
+            }
+            RowOp::Subtract {
+                target,
+                source,
+                factor,
+            } => write!(
+                f,
+                "R{} ← R{} − {factor}·R{}",
+                target + 1,
+                target + 1,
+                source + 1
+            ),
+        }
+    }
+}
+
+/// One recorded operation and the matrix right after it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowOpStep<K> {
+    pub op: RowOp<K>,
+    pub matrix: Matrix<K>,
+}
+
+/// The elementary operations of an elimination, in order, each with the
+/// intermediate matrix it produced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowOpLog<K> {
+    shape: (usize, usize),
+    steps: Vec<RowOpStep<K>>,
+}
+
+impl<K: Field> RowOpLog<K> {
+    /// An empty log for operations on a matrix of shape `shape`.
+    pub fn new(shape: (usize, usize)) -> Self {
+        RowOpLog {
+            shape,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Records `op` together with the matrix it produced.
+    pub fn push(&mut self, op: RowOp<K>, matrix: &Matrix<K>) {
+        self.steps.push(RowOpStep {
+            op,
+            matrix: matrix.clone(),
+        });
+    }
+
+    /// Shape of the matrix the operations were recorded on.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    pub fn steps(&self) -> &[RowOpStep<K>] {
+        &self.steps
+    }
+
+    pub fn ops(&self) -> impl Iterator<Item = &RowOp<K>> {
+        self.steps.iter().map(|step| &step.op)
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Applies the same operations, in order, to a copy of `matrix`, which
+    /// must have as many rows as the recorded one. Replaying an inversion
+    /// log on the identity gives the inverse.
+    pub fn replay(&self, matrix: &Matrix<K>) -> Result<Matrix<K>, LinalgError> {
+        if matrix.shape().0 != self.shape.0 {
+            return Err(LinalgError::ShapeMismatch {
+                left: self.shape,
+                right: matrix.shape(),
+            });
+        }
+        let mut result = matrix.clone();
+        for op in self.ops() {
+            op.apply(&mut result);
+        }
+        Ok(result)
+    }
+}
+
+impl<K: fmt::Display> fmt::Display for RowOpLog<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, step) in self.steps.iter().enumerate() {
+            if index != 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}. {}", index + 1, step.op)?;
+            writeln!(f, "{}", step.matrix)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: Field> Matrix<K> {
+    /// `echelon` together with the operations it performed.
+    pub fn echelon_with_log(&self) -> (Echelon<K>, RowOpLog<K>) {
+        let mut log = RowOpLog::new(self.shape());
+        let echelon = self.eliminate(false, Tolerance::default_for(self.shape()), Some(&mut log));
+        (echelon, log)
+    }
+
+    /// `rref` together with the operations it performed.
+    pub fn rref_with_log(&self) -> (Echelon<K>, RowOpLog<K>) {
+        let mut log = RowOpLog::new(self.shape());
+        let rref = self.eliminate(true, Tolerance::default_for(self.shape()), Some(&mut log));
+        (rref, log)
+    }
+
+    /// Gauss–Jordan inverse: the operations reducing `A` to the identity,
+    /// replayed on the identity. Fails with `NotSquare` or `Singular`.
+    pub fn inverse_with_log(&self) -> Result<(Matrix<K>, RowOpLog<K>), LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare {
+                shape: self.shape(),
+            });
+        }
+        let (rref, log) = self.rref_with_log();
+        if rref.rank() < self.shape().0 {
+            return Err(LinalgError::Singular);
+        }
+        let inverse = log.replay(&Matrix::identity(self.shape().0))?;
+        Ok((inverse, log))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational;
+
+    #[test]
+    fn row_op_log() {
+        let a = Matrix::from(&[&[1., 2.], &[4., 6.]]);
+        let (rref, log) = a.rref_with_log();
+        let ops: Vec<RowOp<f64>> = log.ops().copied().collect();
+        assert_eq!(
+            ops,
+            vec![
+                RowOp::Divide {
+                    row: 1,
+                    divisor: 4.
+                },
+                RowOp::Swap(0, 1),
+                RowOp::Subtract {
+                    target: 1,
+                    source: 0,
+                    factor: 1.
+                },
+                RowOp::Divide {
+                    row: 1,
+                    divisor: 0.5
+                },
+                RowOp::Subtract {
+                    target: 0,
+                    source: 1,
+                    factor: 1.5
+                },
+            ]
+        );
+        assert_eq!(log.steps().last().unwrap().matrix, *rref.matrix());
+        assert_eq!(log.replay(&a).unwrap(), Matrix::identity(2));
+        assert_eq!(rref.into_matrix(), a.clone().row_echelon());
+
+        let (echelon, log) = a.echelon_with_log();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.replay(&a).unwrap(), *echelon.matrix());
+        assert_eq!(
+            log.replay(&Matrix::zeros(3, 3)),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 2),
+                right: (3, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn inverse_with_log() {
+        let r = Rational::from;
+        let a = Matrix::from(&[&[r(2), r(1)], &[r(1), r(1)]]);
+        let (inverse, log) = a.inverse_with_log().unwrap();
+        assert_eq!(&a * &inverse, Matrix::identity(2));
+        assert!(!log.is_empty());
+
+        let singular = Matrix::from(&[&[1., 2.], &[2., 4.]]);
+        assert_eq!(
+            singular.inverse_with_log().map(|(m, _)| m),
+            Err(LinalgError::Singular)
+        );
+    }
+
+    #[test]
+    fn row_op_display() {
+        assert_eq!(RowOp::<i32>::Swap(0, 2).to_string(), "R1 ↔ R3");
+        let subtract = RowOp::Subtract {
+            target: 1,
+            source: 0,
+            factor: 2,
+        };
+        assert_eq!(subtract.to_string(), "R2 ← R2 − 2·R1");
+        let divide = RowOp::Divide { row: 0, divisor: 4 };
+        assert_eq!(divide.to_string(), "R1 ← R1 / 4");
+
+        let a = Matrix::from(&[&[0., 1.], &[1., 0.]]);
+        let (_, log) = a.echelon_with_log();
+        assert_eq!(log.to_string(), "1. R1 ↔ R2\n[\n[1,0]\n[0,1]\n]\n");
+    }
+}
@@ -0,0 +1,260 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The arithmetic every `Vector` and `Matrix` entry must support.
+///
+/// Implemented for `f32`, `f64`, the signed integer types, `Rational` and
+/// `Complex`. Integer implementations follow integer semantics: `sqrt` is
+/// the floor square root and panics on a negative value, `recip` truncates
+/// to zero for anything but `±1` and panics on zero, and `epsilon` is zero
+/// since integer arithmetic is exact. The algorithms that rely on a true
+/// division or square root are therefore bounded by `Field` or `Float`,
+/// which the integer types do not implement.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Ordered type holding magnitudes, `Self` for real scalars.
+    type Real: Real;
+
+    /// Additive identity.
+    fn zero() -> Self;
+
+    /// Multiplicative identity.
+    fn one() -> Self;
+
+    /// Magnitude of the value, the modulus for complex numbers.
+    fn abs(self) -> Self::Real;
+
+    /// Square root, principal for complex numbers. What a negative real
+    /// gives depends on the type: NaN for floats, a panic for integers and
+    /// `Rational`.
+    fn sqrt(self) -> Self;
+
+    /// Multiplicative inverse `1 / self`.
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    /// Smallest difference the type can tell apart from zero around one.
+    fn epsilon() -> Self::Real;
+
+    /// Complex conjugate, the identity for real scalars.
+    fn conj(self) -> Self {
+        self
+    }
+
+    /// Real part of the value.
+    fn re(self) -> Self::Real;
+
+    /// The scalar with real part `value`, and no imaginary part.
+    fn from_real(value: Self::Real) -> Self;
+
+    /// Whether the value equals `zero()` exactly.
+    fn is_zero(self) -> bool {
+        self == Self::zero()
+    }
+}
+
+/// Scalars with a true division: every nonzero value has an inverse.
+///
+/// Elimination, `inverse`, `lu` and the solvers are only defined for these,
+/// since they divide by pivots. Integer division truncates, so the integer
+/// types are not fields and get none of them:
+///
+/// ```compile_fail
+/// use matrix::Matrix;
+///
+/// let mut a = Matrix::from(&[&[2, 0], &[0, 2]]);
+/// a.inverse();
+/// ```
+pub trait Field: Scalar {}
+
+/// Totally ordered scalars, usable as magnitudes and pivot keys.
+pub trait Real: Scalar<Real = Self> + PartialOrd {
+    /// Lossy conversion used by the `f32` returning norms and cosine.
+    fn to_f32(self) -> f32;
+
+    /// A count as a real, for scaling tolerances by a matrix size. Floats
+    /// round to the nearest value; integers and `Rational` panic when the
+    /// count does not fit.
+    fn from_usize(value: usize) -> Self;
+}
+
+/// Floating point reals, the scalars the orthogonal and spectral
+/// decompositions are defined for.
+pub trait Float: Real + Field {
+    /// Nearest value to `value`, for writing algorithm constants.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_scalar_float {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                type Real = $t;
+
+                fn zero() -> Self {
+                    0.
+                }
+
+                fn one() -> Self {
+                    1.
+                }
+
+                fn abs(self) -> Self::Real {
+                    <$t>::abs(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn recip(self) -> Self {
+                    <$t>::recip(self)
+                }
+
+                fn epsilon() -> Self::Real {
+                    <$t>::EPSILON
+                }
+
+                fn re(self) -> Self::Real {
+                    self
+                }
+
+                fn from_real(value: Self::Real) -> Self {
+                    value
+                }
+            }
+
+            impl Real for $t {
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+
+                fn from_usize(value: usize) -> Self {
+                    value as $t
+                }
+            }
+
+            impl Field for $t {}
+
+            impl Float for $t {
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_scalar_int {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                type Real = $t;
+
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn abs(self) -> Self::Real {
+                    <$t>::abs(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    <$t>::isqrt(self)
+                }
+
+                fn epsilon() -> Self::Real {
+                    0
+                }
+
+                fn re(self) -> Self::Real {
+                    self
+                }
+
+                fn from_real(value: Self::Real) -> Self {
+                    value
+                }
+            }
+
+            impl Real for $t {
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+
+                fn from_usize(value: usize) -> Self {
+                    <$t>::try_from(value)
+                        .unwrap_or_else(|_| panic!("{value} does not fit in {}", stringify!($t)))
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_float!(f32, f64);
+impl_scalar_int!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rational;
+
+    #[test]
+    fn scalar_identities() {
+        assert_eq!(f32::zero(), 0.);
+        assert_eq!(f64::one(), 1.);
+        assert_eq!(i32::zero(), 0);
+        assert_eq!(i64::one(), 1);
+    }
+
+    #[test]
+    fn scalar_abs_sqrt_recip() {
+        assert_eq!(Scalar::abs(-4.5_f32), 4.5);
+        assert_eq!(Scalar::abs(-7_i32), 7);
+        assert_eq!(Scalar::sqrt(16_f64), 4.);
+        assert_eq!(Scalar::sqrt(17_i32), 4);
+        assert_eq!(Scalar::recip(4_f32), 0.25);
+        assert_eq!(Scalar::recip(4_i32), 0);
+        assert_eq!(Scalar::recip(1_i32), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn integer_sqrt_of_negative() {
+        Scalar::sqrt(-4_i32);
+    }
+
+    #[test]
+    fn scalar_epsilon() {
+        assert_eq!(f32::epsilon(), f32::EPSILON);
+        assert_eq!(f64::epsilon(), f64::EPSILON);
+        assert_eq!(i16::epsilon(), 0);
+    }
+
+    #[test]
+    fn real_from_usize() {
+        assert_eq!(f64::from_usize(12), 12.);
+        assert_eq!(f32::from_usize(0), 0.);
+        assert_eq!(i64::from_usize(7), 7);
+        assert_eq!(Rational::from_usize(3), Rational::integer(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "300 does not fit in i8")]
+    fn integer_from_usize_overflow() {
+        i8::from_usize(300);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::types::SVector;
+use crate::{LinalgError, Matrix, Scalar};
+use std::fmt;
+
+/// A stack-allocated `R × C` matrix whose dimensions are part of its type.
+///
+/// Products only type-check when the inner dimensions agree:
+///
+/// ```
+/// use matrix::types::SMatrix;
+///
+/// let a = SMatrix::new([[1., 2., 3.], [4., 5., 6.]]);
+/// let b = SMatrix::new([[1., 0.], [0., 1.], [1., 1.]]);
+/// let c: SMatrix<f64, 2, 2> = a * b;
+/// assert_eq!(c.positions, [[4., 5.], [10., 11.]]);
+/// ```
+///
+/// ```compile_fail
+/// use matrix::types::SMatrix;
+///
+/// let a = SMatrix::new([[1., 2., 3.], [4., 5., 6.]]);
+/// let b = SMatrix::new([[1., 0.], [0., 1.]]);
+/// let c = a * b; // 2x3 times 2x2
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SMatrix<K, const R: usize, const C: usize> {
+    pub positions: [[K; C]; R],
+}
+
+impl<K: Scalar, const R: usize, const C: usize> std::ops::Add for SMatrix<K, R, C> {
+    type Output = SMatrix<K, R, C>;
+
+    fn add(self, other: SMatrix<K, R, C>) -> SMatrix<K, R, C> {
+        let mut result = self;
+        SMatrix::add(&mut result, &other);
+        result
+    }
+}
+
+impl<K: Scalar, const R: usize, const C: usize> std::ops::Sub for SMatrix<K, R, C> {
+    type Output = SMatrix<K, R, C>;
+
+    fn sub(self, other: SMatrix<K, R, C>) -> SMatrix<K, R, C> {
+        let mut result = self;
+        SMatrix::sub(&mut result, &other);
+        result
+    }
+}
+
+impl<K: Scalar, const R: usize, const C: usize, const P: usize> std::ops::Mul<SMatrix<K, C, P>>
+    for SMatrix<K, R, C>
+{
+    type Output = SMatrix<K, R, P>;
+
+    fn mul(self, other: SMatrix<K, C, P>) -> SMatrix<K, R, P> {
+        self.mul_mat(&other)
+    }
+}
+
+impl<K: Scalar, const R: usize, const C: usize> std::ops::Mul<SVector<K, C>> for SMatrix<K, R, C> {
+    type Output = SVector<K, R>;
+
+    fn mul(self, vec: SVector<K, C>) -> SVector<K, R> {
+        self.mul_vec(&vec)
+    }
+}
+
+impl<K: Scalar, const R: usize, const C: usize> From<[[K; C]; R]> for SMatrix<K, R, C> {
+    fn from(positions: [[K; C]; R]) -> Self {
+        SMatrix { positions }
+    }
+}
+
+impl<K: Scalar, const R: usize, const C: usize> From<SMatrix<K, R, C>> for Matrix<K> {
+    fn from(matrix: SMatrix<K, R, C>) -> Self {
+        matrix.to_matrix()
+    }
+}
+
+impl<K: Scalar, const R: usize, const C: usize> TryFrom<Matrix<K>> for SMatrix<K, R, C> {
+    type Error = LinalgError;
+
+    fn try_from(matrix: Matrix<K>) -> Result<Self, LinalgError> {
+        if matrix.shape() != (R, C) {
+            return Err(LinalgError::ShapeMismatch {
+                left: (R, C),
+                right: matrix.shape(),
+            });
+        }
+        let mut result = SMatrix::zeros();
+        for (row, values) in result.positions.iter_mut().zip(matrix.rows()) {
+            row.copy_from_slice(values);
+        }
+        Ok(result)
+    }
+}
+
+impl<K: fmt::Display, const R: usize, const C: usize> fmt::Display for SMatrix<K, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[")?;
+        for row in self.positions.iter() {
+            write!(f, "[")?;
+            for (index, point) in row.iter().enumerate() {
+                if index != 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "{}", point)?;
+            }
+            writeln!(f, "]")?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<K: Scalar, const R: usize, const C: usize> SMatrix<K, R, C> {
+    pub fn new(positions: [[K; C]; R]) -> Self {
+        SMatrix { positions }
+    }
+
+    pub fn zeros() -> Self {
+        SMatrix {
+            positions: [[K::zero(); C]; R],
+        }
+    }
+
+    /// `(rows, cols)`, known at compile time.
+    pub fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    pub fn add(&mut self, v: &SMatrix<K, R, C>) {
+        for (row, other_row) in self.positions.iter_mut().zip(v.positions.iter()) {
+            for (point, &other_point) in row.iter_mut().zip(other_row.iter()) {
+                *point = *point + other_point;
+            }
+        }
+    }
+
+    pub fn sub(&mut self, v: &SMatrix<K, R, C>) {
+        for (row, other_row) in self.positions.iter_mut().zip(v.positions.iter()) {
+            for (point, &other_point) in row.iter_mut().zip(other_row.iter()) {
+                *point = *point - other_point;
+            }
+        }
+    }
+
+    pub fn scl(&mut self, a: K) {
+        for row in self.positions.iter_mut() {
+            for point in row.iter_mut() {
+                *point = *point * a;
+            }
+        }
+    }
+
+    pub fn mul_vec(&self, vec: &SVector<K, C>) -> SVector<K, R> {
+        let mut result = SVector::zeros();
+        for (value, row) in result.positions.iter_mut().zip(self.positions.iter()) {
+            for (&point, &other_point) in row.iter().zip(vec.positions.iter()) {
+                *value = *value + point * other_point;
+            }
+        }
+        result
+    }
+
+    pub fn mul_mat<const P: usize>(&self, mat: &SMatrix<K, C, P>) -> SMatrix<K, R, P> {
+        let mut result = SMatrix::zeros();
+        for row in 0..R {
+            for inner in 0..C {
+                let factor = self.positions[row][inner];
+                for column in 0..P {
+                    result.positions[row][column] =
+                        result.positions[row][column] + factor * mat.positions[inner][column];
+                }
+            }
+        }
+        result
+    }
+
+    pub fn transpose(&self) -> SMatrix<K, C, R> {
+        let mut result = SMatrix::zeros();
+        for row in 0..R {
+            for column in 0..C {
+                result.positions[column][row] = self.positions[row][column];
+            }
+        }
+        result
+    }
+
+    pub fn to_matrix(&self) -> Matrix<K> {
+        let mut result = Matrix::zeros(R, C);
+        for (row, values) in self.positions.iter().enumerate() {
+            result.row_mut(row).copy_from_slice(values);
+        }
+        result
+    }
+}
+
+impl<K: Scalar, const N: usize> SMatrix<K, N, N> {
+    pub fn identity() -> Self {
+        let mut result = SMatrix::zeros();
+        for i in 0..N {
+            result.positions[i][i] = K::one();
+        }
+        result
+    }
+
+    pub fn trace(&self) -> K {
+        let mut result = K::zero();
+        for i in 0..N {
+            result = result + self.positions[i][i];
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smatrix_arithmetic() {
+        let mut u = SMatrix::new([[1., 2.], [3., 4.]]);
+        let v = SMatrix::new([[7., 4.], [-2., 2.]]);
+        u.add(&v);
+        assert_eq!(u.positions, [[8., 6.], [1., 6.]]);
+        u.sub(&v);
+        assert_eq!(u.positions, [[1., 2.], [3., 4.]]);
+        u.scl(2.);
+        assert_eq!(u.positions, [[2., 4.], [6., 8.]]);
+        assert_eq!((u + v).positions, [[9., 8.], [4., 10.]]);
+        assert_eq!((u - v).positions, [[-5., 0.], [8., 6.]]);
+        assert_eq!(u.trace(), 10.);
+    }
+
+    #[test]
+    fn smatrix_products() {
+        let u = SMatrix::new([[0., 4., -2.], [-4., -3., 0.]]);
+        let v = SMatrix::new([[0., 1.], [1., -1.], [2., 3.]]);
+        assert_eq!((u * v).positions, [[0., -10.], [-3., -1.]]);
+
+        let u = SMatrix::new([[2., -2.], [-2., 2.]]);
+        let w = SVector::new([4., 2.]);
+        assert_eq!((u * w).positions, [4., -4.]);
+        assert_eq!(SMatrix::identity() * w, w);
+
+        let u = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let t: SMatrix<i32, 3, 2> = u.transpose();
+        assert_eq!(t.positions, [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn smatrix_conversions() {
+        let u = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let m: Matrix<i32> = u.into();
+        assert_eq!(m, Matrix::from(&[&[1, 2, 3], &[4, 5, 6]]));
+        assert_eq!(SMatrix::<i32, 2, 3>::try_from(m.clone()), Ok(u));
+        assert_eq!(
+            SMatrix::<i32, 3, 2>::try_from(m),
+            Err(LinalgError::ShapeMismatch {
+                left: (3, 2),
+                right: (2, 3)
+            })
+        );
+        assert_eq!(u.to_string(), "[\n[1,2,3]\n[4,5,6]\n]");
+    }
+}
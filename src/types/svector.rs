@@ -0,0 +1,167 @@
+use crate::{LinalgError, Scalar, Vector};
+use std::fmt;
+
+/// A stack-allocated vector whose size `N` is part of its type, so size
+/// mismatches are compile errors instead of runtime panics.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SVector<K, const N: usize> {
+    pub positions: [K; N],
+}
+
+impl<K: Scalar, const N: usize> std::ops::Add for SVector<K, N> {
+    type Output = SVector<K, N>;
+
+    fn add(self, other: SVector<K, N>) -> SVector<K, N> {
+        let mut result = self;
+        SVector::add(&mut result, &other);
+        result
+    }
+}
+
+impl<K: Scalar, const N: usize> std::ops::Sub for SVector<K, N> {
+    type Output = SVector<K, N>;
+
+    fn sub(self, other: SVector<K, N>) -> SVector<K, N> {
+        let mut result = self;
+        SVector::sub(&mut result, &other);
+        result
+    }
+}
+
+impl<K: Scalar, const N: usize> From<[K; N]> for SVector<K, N> {
+    fn from(positions: [K; N]) -> Self {
+        SVector { positions }
+    }
+}
+
+impl<K: Scalar, const N: usize> From<SVector<K, N>> for Vector<K> {
+    fn from(vector: SVector<K, N>) -> Self {
+        Vector::from(&vector.positions)
+    }
+}
+
+impl<K: Scalar, const N: usize> TryFrom<Vector<K>> for SVector<K, N> {
+    type Error = LinalgError;
+
+    fn try_from(vector: Vector<K>) -> Result<Self, LinalgError> {
+        let positions: [K; N] = vector.positions.try_into().map_err(|positions: Vec<K>| {
+            LinalgError::ShapeMismatch {
+                left: (N, 1),
+                right: (positions.len(), 1),
+            }
+        })?;
+        Ok(SVector { positions })
+    }
+}
+
+impl<K: fmt::Display, const N: usize> fmt::Display for SVector<K, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, pos) in self.positions.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", pos)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<K: Scalar, const N: usize> SVector<K, N> {
+    pub fn new(positions: [K; N]) -> Self {
+        SVector { positions }
+    }
+
+    pub fn zeros() -> Self {
+        SVector {
+            positions: [K::zero(); N],
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        N
+    }
+
+    pub fn add(&mut self, v: &SVector<K, N>) {
+        for (point, &other_point) in self.positions.iter_mut().zip(v.positions.iter()) {
+            *point = *point + other_point;
+        }
+    }
+
+    pub fn sub(&mut self, v: &SVector<K, N>) {
+        for (point, &other_point) in self.positions.iter_mut().zip(v.positions.iter()) {
+            *point = *point - other_point;
+        }
+    }
+
+    pub fn scl(&mut self, a: K) {
+        for point in self.positions.iter_mut() {
+            *point = *point * a;
+        }
+    }
+
+    /// Inner product, conjugating `self` like `Vector::dot`.
+    pub fn dot(&self, v: &SVector<K, N>) -> K {
+        let mut result = K::zero();
+        for (point, &other_point) in self.positions.iter().zip(v.positions.iter()) {
+            result = result + point.conj() * other_point;
+        }
+        result
+    }
+
+    pub fn to_vector(&self) -> Vector<K> {
+        Vector::from(&self.positions)
+    }
+}
+
+impl<K: Scalar> SVector<K, 3> {
+    pub fn cross(&self, v: &SVector<K, 3>) -> SVector<K, 3> {
+        let [ux, uy, uz] = self.positions;
+        let [vx, vy, vz] = v.positions;
+        SVector::new([uy * vz - uz * vy, uz * vx - ux * vz, ux * vy - uy * vx])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svector_arithmetic() {
+        let mut u = SVector::new([2., 3.]);
+        let v = SVector::from([5., 7.]);
+        u.add(&v);
+        assert_eq!(u.positions, [7., 10.]);
+        u.sub(&v);
+        assert_eq!(u.positions, [2., 3.]);
+        u.scl(2.);
+        assert_eq!(u.positions, [4., 6.]);
+        assert_eq!((u + v).positions, [9., 13.]);
+        assert_eq!((u - v).positions, [-1., -1.]);
+        assert_eq!(u.dot(&v), 62.);
+        assert_eq!(SVector::<i32, 4>::zeros().size(), 4);
+    }
+
+    #[test]
+    fn svector_cross() {
+        let u = SVector::new([4., 2., -3.]);
+        let v = SVector::new([-2., -5., 16.]);
+        assert_eq!(u.cross(&v).positions, [17., -58., -16.]);
+    }
+
+    #[test]
+    fn svector_conversions() {
+        let u = SVector::new([1, 2, 3]);
+        let v: Vector<i32> = u.into();
+        assert_eq!(v.positions, vec![1, 2, 3]);
+        assert_eq!(SVector::<i32, 3>::try_from(v.clone()), Ok(u));
+        assert_eq!(
+            SVector::<i32, 2>::try_from(v),
+            Err(LinalgError::ShapeMismatch {
+                left: (2, 1),
+                right: (3, 1)
+            })
+        );
+        assert_eq!(u.to_string(), "[1, 2, 3]");
+    }
+}
@@ -0,0 +1,96 @@
+use crate::types::{Matrix, Real, Scalar};
+
+/// When a computed entry counts as zero during elimination: `|x| ≤ absolute
+/// + relative·‖A‖`, with `‖A‖` the largest entry modulus of the input.
+///
+/// Exact scalars have a zero epsilon, so their default tolerance is exact
+/// and rational or integer results are unchanged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance<R> {
+    pub absolute: R,
+    pub relative: R,
+}
+
+impl<R: Real> Tolerance<R> {
+    pub fn new(absolute: R, relative: R) -> Self {
+        Tolerance { absolute, relative }
+    }
+
+    /// Only exact zeros are zero.
+    pub fn exact() -> Self {
+        Tolerance::new(R::zero(), R::zero())
+    }
+
+    /// `max(m, n)·ε` relative to the matrix norm, the usual cutoff for
+    /// rounding errors accumulated by an `m × n` elimination.
+    pub fn default_for((rows, cols): (usize, usize)) -> Self {
+        Tolerance::new(R::zero(), R::from_usize(rows.max(cols)) * R::epsilon())
+    }
+
+    /// The largest magnitude treated as zero in `matrix`.
+    pub fn threshold<K: Scalar<Real = R>>(&self, matrix: &Matrix<K>) -> R {
+        let mut norm = R::zero();
+        for &value in matrix.as_slice() {
+            if value.abs() > norm {
+                norm = value.abs();
+            }
+        }
+        self.absolute + self.relative * norm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LinalgError, Rational, Vector};
+
+    #[test]
+    fn tolerance_threshold() {
+        let a = Matrix::from(&[&[1., -4.], &[2., 3.]]);
+        assert_eq!(Tolerance::new(0.5, 0.25).threshold(&a), 1.5);
+        assert_eq!(Tolerance::exact().threshold(&a), 0.);
+        let default = Tolerance::default_for(a.shape());
+        assert_eq!(default.threshold(&a), 8. * f64::EPSILON);
+
+        let r = Matrix::from(&[&[Rational::new(1, 3)]]);
+        assert_eq!(
+            Tolerance::default_for(r.shape()).threshold(&r),
+            Rational::zero()
+        );
+    }
+
+    #[test]
+    fn tolerance_singularity() {
+        // singular up to rounding
+        let a = Matrix::from(&[&[1., 2., 3.], &[4., 5., 6.], &[7., 8., 9.]]);
+        assert!(a.lu().unwrap().is_singular());
+        assert_eq!(a.rref().rank(), 2);
+        let solution = a.solve(&Vector::from(&[6., 15., 24.])).unwrap();
+        assert!(!solution.unique);
+        assert_eq!(
+            a.solve(&Vector::from(&[1., 0., 0.]))
+                .map(|s| s.value.positions),
+            Err(LinalgError::Singular)
+        );
+        // which an exact comparison misses
+        assert!(!a.lu_with(Tolerance::exact()).unwrap().is_singular());
+
+        // nearly singular: full rank by default, rank one at a loose tolerance
+        let a = Matrix::from(&[&[1., 1.], &[1., 1. + 1e-12]]);
+        let loose = Tolerance::new(0., 1e-9);
+        assert_eq!(a.rank_with(Tolerance::default_for(a.shape())), 2);
+        assert!(a.inverse_with(Tolerance::default_for(a.shape())).is_ok());
+        assert_eq!(a.rank_with(loose), 1);
+        assert_eq!(a.inverse_with(loose), Err(LinalgError::Singular));
+        assert!(a.lu_with(loose).unwrap().is_singular());
+        let solution = a.solve_with(&Vector::from(&[2., 2.]), loose).unwrap();
+        assert!(!solution.unique);
+        assert_eq!(solution.value.positions[1], 0.);
+
+        let absolute = Tolerance::new(1e-6, 0.);
+        assert_eq!(
+            Matrix::from(&[&[1e-7, 0.], &[0., 1.]]).rank_with(absolute),
+            1
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{LinalgError, Real, Scalar};
 use std::fmt;
 
 #[derive(Clone)]
@@ -5,42 +6,6 @@ pub struct Vector<K> {
     pub positions: Vec<K>,
 }
 
-impl<K: std::ops::Add<Output = K> + Copy> std::ops::Add for Vector<K> {
-    type Output = Vector<K>;
-
-    fn add(self, other: Vector<K>) -> Vector<K> {
-        let mut result = self;
-        for (point, &other_point) in result.positions.iter_mut().zip(other.positions.iter()) {
-            *point = *point + other_point;
-        }
-        result
-    }
-}
-
-impl<K: std::ops::Sub<Output = K> + Copy> std::ops::Sub for Vector<K> {
-    type Output = Vector<K>;
-
-    fn sub(self, other: Vector<K>) -> Vector<K> {
-        let mut result = self;
-        for (point, &other_point) in result.positions.iter_mut().zip(other.positions.iter()) {
-            *point = *point - other_point;
-        }
-        result
-    }
-}
-
-impl<K: std::ops::Mul<f32, Output = K> + Copy> std::ops::Mul<f32> for Vector<K> {
-    type Output = Vector<K>;
-
-    fn mul(self, scalar: f32) -> Vector<K> {
-        let mut result = self;
-        for point in result.positions.iter_mut() {
-            *point = *point * scalar;
-        }
-        result
-    }
-}
-
 impl<K: fmt::Display> fmt::Display for Vector<K> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "[").expect("can't write in stdout");
@@ -54,10 +19,7 @@ impl<K: fmt::Display> fmt::Display for Vector<K> {
     }
 }
 
-impl<
-        K: Copy + std::ops::Add<Output = K> + std::ops::Sub<Output = K> + std::ops::Mul<Output = K>,
-    > Vector<K>
-{
+impl<K: Scalar> Vector<K> {
     pub fn size(&self) -> usize {
         self.positions.len()
     }
@@ -68,24 +30,40 @@ impl<
         }
     }
 
-    fn is_same_size(&self, v: &Vector<K>) {
+    fn is_same_size(&self, v: &Vector<K>) -> Result<(), LinalgError> {
         if self.positions.len() != v.positions.len() {
-            panic!("Can't add two Vectors of different sizes");
+            return Err(LinalgError::ShapeMismatch {
+                left: (self.size(), 1),
+                right: (v.size(), 1),
+            });
         }
+        Ok(())
     }
 
     pub fn add(&mut self, v: &Vector<K>) {
-        self.is_same_size(v);
+        self.try_add(v)
+            .unwrap_or_else(|e| panic!("Can't add Vectors: {e}"));
+    }
+
+    pub fn try_add(&mut self, v: &Vector<K>) -> Result<(), LinalgError> {
+        self.is_same_size(v)?;
         for (index, points) in v.positions.iter().enumerate() {
             self.positions[index] = self.positions[index] + *points;
         }
+        Ok(())
     }
 
     pub fn sub(&mut self, v: &Vector<K>) {
-        self.is_same_size(v);
+        self.try_sub(v)
+            .unwrap_or_else(|e| panic!("Can't subtract Vectors: {e}"));
+    }
+
+    pub fn try_sub(&mut self, v: &Vector<K>) -> Result<(), LinalgError> {
+        self.is_same_size(v)?;
         for (index, _points) in v.positions.iter().enumerate() {
             self.positions[index] = self.positions[index] - v.positions[index];
         }
+        Ok(())
     }
 
     pub fn scl(&mut self, a: K) {
@@ -94,29 +72,32 @@ impl<
         }
     }
 
+    /// Inner product, conjugating `self` so that complex vectors get the
+    /// Hermitian product and `u.dot(u)` is the squared norm.
     pub fn dot(&self, v: Vector<K>) -> K {
-        if self.positions.len() != v.positions.len() {
-            panic!("vectors must have the same size for dot product");
-        }
+        self.try_dot(&v)
+            .unwrap_or_else(|e| panic!("Can't compute dot product: {e}"))
+    }
+
+    pub fn try_dot(&self, v: &Vector<K>) -> Result<K, LinalgError> {
+        self.is_same_size(v)?;
         if self.size() == 0 {
-            panic!("vector is empty");
+            return Err(LinalgError::Empty);
         }
-        let mut result = self.positions[0] * v.positions[0];
+        let mut result = self.positions[0].conj() * v.positions[0];
         for i in 1..self.positions.len() {
-            result = result + (self.positions[i] * v.positions[i])
+            result = result + (self.positions[i].conj() * v.positions[i])
         }
-        result
+        Ok(result)
     }
-}
 
-impl<K: Clone + std::ops::Mul<K, Output = K> + Into<f32>> Vector<K> {
     pub fn norm_1(&self) -> f32 {
         if self.positions.is_empty() {
             panic!("vector is empty");
         }
-        let mut result = self.abs(self.positions[0].clone());
+        let mut result = self.abs(self.positions[0]);
         for index in 1..self.positions.len() {
-            result += self.abs(self.positions[index].clone());
+            result += self.abs(self.positions[index]);
         }
         result
     }
@@ -125,9 +106,9 @@ impl<K: Clone + std::ops::Mul<K, Output = K> + Into<f32>> Vector<K> {
         if self.positions.is_empty() {
             panic!("vector is empty");
         }
-        let mut result: f32 = self.abs(self.positions[0].clone()).powf(2.);
+        let mut result: f32 = self.abs(self.positions[0]).powf(2.);
         for index in 1..self.positions.len() {
-            result += self.abs(self.positions[index].clone()).powf(2.);
+            result += self.abs(self.positions[index]).powf(2.);
         }
         // square root of result Newton-Raphson algorithm
         if result < 0. {
@@ -157,9 +138,9 @@ impl<K: Clone + std::ops::Mul<K, Output = K> + Into<f32>> Vector<K> {
         if self.positions.is_empty() {
             panic!("vector is empty");
         }
-        let mut result: f32 = self.abs(self.positions[0].clone());
+        let mut result: f32 = self.abs(self.positions[0]);
         for index in 1..self.positions.len() {
-            let elt: f32 = self.abs(self.positions[index].clone());
+            let elt: f32 = self.abs(self.positions[index]);
             if elt > result {
                 result = elt;
             }
@@ -168,16 +149,14 @@ impl<K: Clone + std::ops::Mul<K, Output = K> + Into<f32>> Vector<K> {
     }
 
     fn abs(&self, val: K) -> f32 {
-        if val.clone().into() < 0. {
-            return -val.into();
-        }
-        val.into()
+        val.abs().to_f32()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Complex;
 
     #[test]
     fn vector_add() {
@@ -331,12 +310,12 @@ mod tests {
 
         let u = Vector::from(&[1., 2., 3.]);
         assert_eq!(u.norm_1(), 6.0);
-        assert_eq!(u.norm(), 3.7416573);
+        crate::assert_approx_eq!(u.norm(), 3.7416573);
         assert_eq!(u.norm_inf(), 3.);
 
         let u = Vector::from(&[-1., -2.]);
         assert_eq!(u.norm_1(), 3.0);
-        assert_eq!(u.norm(), 2.236067977);
+        crate::assert_approx_eq!(u.norm(), 2.236_068);
         assert_eq!(u.norm_inf(), 2.);
     }
 
@@ -364,17 +343,48 @@ mod tests {
 
         let u = Vector::from(&[2., 1.]);
         assert_eq!(u.norm_1(), 3.);
-        assert_eq!(u.norm(), 2.236067977);
+        crate::assert_approx_eq!(u.norm(), 2.236_068);
         assert_eq!(u.norm_inf(), 2.);
 
         let u = Vector::from(&[4., 2.]);
         assert_eq!(u.norm_1(), 6.);
-        assert_eq!(u.norm(), 4.472135955);
+        crate::assert_approx_eq!(u.norm(), 4.472_136);
         assert_eq!(u.norm_inf(), 4.);
 
         let u = Vector::from(&[-4., -2.]);
         assert_eq!(u.norm_1(), 6.);
-        assert_eq!(u.norm(), 4.472135955);
+        crate::assert_approx_eq!(u.norm(), 4.472_136);
         assert_eq!(u.norm_inf(), 4.);
     }
+
+    #[test]
+    fn complex_dot_and_norms() {
+        let u = Vector::from(&[Complex::new(1., 1.), Complex::new(0., 2.)]);
+        let v = Vector::from(&[Complex::new(2., 0.), Complex::new(1., 1.)]);
+        assert_eq!(u.dot(v.clone()), Complex::new(4., -4.));
+        assert_eq!(u.dot(u.clone()), Complex::from(6.));
+
+        let u = Vector::from(&[Complex::new(3., 4.), Complex::new(0., -12.)]);
+        assert_eq!(u.norm_1(), 17.);
+        assert_eq!(u.norm(), 13.);
+        assert_eq!(u.norm_inf(), 12.);
+    }
+
+    #[test]
+    fn vector_errors() {
+        let mut u = Vector::from(&[1, 2]);
+        let v = Vector::from(&[1, 2, 3]);
+        let mismatch = LinalgError::ShapeMismatch {
+            left: (2, 1),
+            right: (3, 1),
+        };
+        assert_eq!(u.try_add(&v), Err(mismatch.clone()));
+        assert_eq!(u.try_sub(&v), Err(mismatch.clone()));
+        assert_eq!(u.try_dot(&v), Err(mismatch));
+        assert_eq!(u.positions, vec![1, 2]);
+
+        let empty: Vector<i32> = Vector::from(&[]);
+        assert_eq!(empty.try_dot(&empty), Err(LinalgError::Empty));
+        assert_eq!(u.try_dot(&Vector::from(&[3, 4])), Ok(11));
+    }
 }